@@ -0,0 +1,318 @@
+//! ring backed by an array that needs no heap
+
+use core::mem::MaybeUninit;
+use core::cmp;
+
+use super::{combine_wrapping, count, drop_wrapping, pop_wrapping, read_wrapping, wrap_index, wrapped_slices,
+            write_default_wrapping, write_front_wrapping, write_wrapping};
+use super::bounded::{push_with, Bounded};
+use super::{Overflow, SliceRing, SliceRingExt, TryReserveError};
+
+/// ring stored inline in an array of `N` slots.
+/// never allocates. works without a heap.
+/// useful on microcontrollers and in real-time threads.
+///
+/// `N` must be a power of two.
+/// like [SliceRingImpl](struct.SliceRingImpl.html) one slot
+/// always stays empty so the ring holds at most `N - 1` elements.
+///
+/// pushing more elements than fit is handled according to an
+/// [Overflow](enum.Overflow.html) policy.
+/// [SliceRing::push_many_back](trait.SliceRing.html#tymethod.push_many_back)
+/// panics on overflow.
+/// [SliceRingExt::try_push_many_back](trait.SliceRingExt.html#tymethod.try_push_many_back)
+/// returns an error instead.
+///
+/// ```
+/// use strider::{ArraySliceRing, Overflow, SliceRing};
+///
+/// let mut ring = ArraySliceRing::<i16, 8>::new();
+/// assert_eq!(ring.push_many_back_with(&[1, 2, 3, 4, 5, 6, 7, 8, 9], Overflow::Reject), 7);
+/// assert_eq!(ring.drop_many_front(5), 5);
+/// let mut output = [0; 2];
+/// ring.read_many_front(&mut output);
+/// assert_eq!(output, [6, 7]);
+/// ```
+///
+/// sizes that are not a power of two are rejected at compile time:
+///
+/// ```compile_fail
+/// let ring = strider::ArraySliceRing::<u8, 6>::new();
+/// ```
+pub struct ArraySliceRing<T, const N: usize> {
+    /// index into `buf` of the first element that could be read.
+    first_readable: usize,
+    /// index into `buf` where the next element could we written.
+    next_writable: usize,
+    /// only the slots between `first_readable` and `next_writable`
+    /// are initialized.
+    buf: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize> ArraySliceRing<T, N> {
+    const N_IS_POWER_OF_TWO: () = assert!(N.is_power_of_two(),
+                                          "ArraySliceRing size must be a power of two");
+
+    /// creates an empty `ArraySliceRing`
+    pub const fn new() -> ArraySliceRing<T, N> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::N_IS_POWER_OF_TWO;
+        ArraySliceRing {
+            first_readable: 0,
+            next_writable: 0,
+            buf: [const { MaybeUninit::uninit() }; N],
+        }
+    }
+
+    /// the maximum number of elements this ring holds
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        N - 1
+    }
+
+    /// returns the number of elements in the ring
+    #[inline]
+    pub fn len(&self) -> usize {
+        count(self.first_readable, self.next_writable, N)
+    }
+
+    /// returns `true` if the ring contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.first_readable == self.next_writable
+    }
+
+    /// returns `true` if no more elements fit into the ring
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// returns how many more elements fit into the ring
+    #[inline]
+    pub fn free(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.buf.as_ptr() as *const T
+    }
+
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as *mut T
+    }
+
+    /// returns the first `count` elements as two slices without copying them.
+    /// the second slice is empty unless the elements wrap around
+    /// the end of the array.
+    // `O(1)`
+    #[inline]
+    pub fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        let count = cmp::min(self.len(), count);
+        unsafe {
+            wrapped_slices(self.ptr(), N, self.first_readable, count)
+        }
+    }
+
+    /// removes and drops `count` elements from the front.
+    /// returns how many elements were dropped.
+    fn drop_front(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len(), count);
+        let start = self.first_readable;
+        self.first_readable = wrap_index(start.wrapping_add(count), N);
+        unsafe {
+            drop_wrapping(self.ptr_mut(), N, start, count);
+        }
+        count
+    }
+
+    /// moves up to `output.len()` elements from the front into `output`.
+    /// the values `output` held before are dropped.
+    /// returns how many elements were moved.
+    /// doesn't clone so `T` doesn't need to be `Clone`.
+    // `O(min(self.len(), output.len()))`
+    pub fn pop_many_front(&mut self, output: &mut [T]) -> usize {
+        let count = cmp::min(self.len(), output.len());
+        let buf = self.ptr();
+        unsafe {
+            pop_wrapping(buf, N, &mut self.first_readable, &mut output[..count]);
+        }
+        count
+    }
+
+    /// removes and drops `count` elements from the back.
+    /// returns how many elements were dropped.
+    fn drop_back(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len(), count);
+        self.next_writable = wrap_index(self.next_writable.wrapping_sub(count), N);
+        let start = self.next_writable;
+        unsafe {
+            drop_wrapping(self.ptr_mut(), N, start, count);
+        }
+        count
+    }
+
+    /// removes and drops all elements
+    pub fn clear(&mut self) {
+        let len = self.len();
+        self.drop_front(len);
+        self.first_readable = 0;
+        self.next_writable = 0;
+    }
+}
+
+impl<T: Clone, const N: usize> ArraySliceRing<T, N> {
+    /// clones `input` into the free space after the back.
+    /// there must be enough free space.
+    fn write_back(&mut self, input: &[T]) {
+        debug_assert!(input.len() <= self.free());
+        let buf = self.ptr_mut();
+        unsafe {
+            write_wrapping(buf, N, &mut self.next_writable, input);
+        }
+    }
+
+    /// clones `input` into the free space before the front.
+    /// there must be enough free space.
+    fn write_front(&mut self, input: &[T]) {
+        debug_assert!(input.len() <= self.free());
+        let buf = self.ptr_mut();
+        unsafe {
+            write_front_wrapping(buf, N, &mut self.first_readable, input);
+        }
+    }
+
+    /// appends `values` to the back of this ring.
+    /// handles overflow according to `overflow`.
+    /// returns how many values were appended.
+    pub fn push_many_back_with(&mut self, values: &[T], overflow: Overflow) -> usize {
+        push_with(self, values, overflow, true)
+    }
+
+    /// prepends `values` to the front of this ring.
+    /// handles overflow according to `overflow` like
+    /// [push_many_back_with](#method.push_many_back_with)
+    /// with the ends swapped.
+    /// returns how many values were prepended.
+    pub fn push_many_front_with(&mut self, values: &[T], overflow: Overflow) -> usize {
+        push_with(self, values, overflow, false)
+    }
+}
+
+impl<T, const N: usize> Drop for ArraySliceRing<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArraySliceRing<T, N> {
+    fn default() -> ArraySliceRing<T, N> {
+        ArraySliceRing::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Bounded<T> for ArraySliceRing<T, N> {
+    const NAME: &'static str = "array ring";
+
+    fn len(&self) -> usize {
+        ArraySliceRing::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        ArraySliceRing::capacity(self)
+    }
+
+    fn write_back(&mut self, values: &[T]) {
+        ArraySliceRing::write_back(self, values);
+    }
+
+    fn write_front(&mut self, values: &[T]) {
+        ArraySliceRing::write_front(self, values);
+    }
+
+    fn drop_front(&mut self, count: usize) {
+        ArraySliceRing::drop_front(self, count);
+    }
+
+    fn drop_back(&mut self, count: usize) {
+        ArraySliceRing::drop_back(self, count);
+    }
+}
+
+impl<T: Clone, const N: usize> SliceRing<T> for ArraySliceRing<T, N> {
+    // `O(input.len())`
+    fn push_many_back(&mut self, input: &[T]) {
+        self.push_many_back_with(input, Overflow::Panic);
+    }
+
+    // `O(1)` for types that don't need drop.
+    // `O(count)` otherwise.
+    fn drop_many_front(&mut self, count: usize) -> usize {
+        self.drop_front(count)
+    }
+
+    // `O(min(self.len(), output.len()))`
+    fn read_many_front(&self, output: &mut [T]) -> usize {
+        let real_count = cmp::min(self.len(), output.len());
+        unsafe {
+            read_wrapping(self.ptr(), N, self.first_readable, &mut output[..real_count]);
+        }
+        real_count
+    }
+}
+
+impl<T: Clone, const N: usize> SliceRingExt<T> for ArraySliceRing<T, N> {
+    // `O(input.len())`
+    fn try_push_many_back(&mut self, input: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(input.len())?;
+        self.write_back(input);
+        Ok(())
+    }
+
+    // never allocates
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.free() < additional {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        Ok(())
+    }
+
+    // `O(1)`
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        ArraySliceRing::read_slices_front(self, count)
+    }
+
+    // `O(input.len())` plus the growth.
+    // panics if the values don't fit.
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T)
+    {
+        let end = offset.checked_add(input.len()).expect("capacity overflow");
+        if self.capacity() < end {
+            panic!("array ring overflow: combining up to {} values with a capacity of {}",
+                   end, self.capacity());
+        }
+        let len = self.len();
+        let buf = self.ptr_mut();
+        unsafe {
+            if len < end {
+                write_default_wrapping(buf, N, &mut self.next_writable, end - len);
+            }
+            combine_wrapping(buf, N, wrap_index(self.first_readable.wrapping_add(offset), N),
+                             input, f);
+        }
+    }
+
+    // `O(input.len())`
+    fn push_many_front(&mut self, input: &[T]) {
+        self.push_many_front_with(input, Overflow::Panic);
+    }
+
+    // `O(1)` for types that don't need drop.
+    // `O(count)` otherwise.
+    fn drop_many_back(&mut self, count: usize) -> usize {
+        self.drop_back(count)
+    }
+}
@@ -0,0 +1,281 @@
+//! ring with a fixed capacity that never allocates after construction
+
+use core::cmp;
+
+use super::{DrainFront, SliceRing, SliceRingExt, SliceRingImpl, TryReserveError};
+
+/// what to do when pushing more values than fit into a
+/// [BoundedSliceRing](struct.BoundedSliceRing.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// push as many values as fit and ignore the rest
+    Reject,
+    /// drop the oldest elements from the front to make space.
+    /// if more values are pushed than fit only the last ones are kept.
+    /// this is the classic circular buffer
+    /// which always holds the last `capacity` values.
+    /// every value counts as pushed
+    /// even if a later value of the same push overwrites it right away.
+    /// with a capacity of `0` no value is kept.
+    Overwrite,
+    /// panic and leave the ring unchanged
+    Panic,
+}
+
+/// the primitives the [Overflow](enum.Overflow.html) policies are built on.
+/// implemented by the rings with a fixed capacity.
+pub(crate) trait Bounded<T> {
+    /// prefixes the message of [Overflow::Panic](enum.Overflow.html#variant.Panic)
+    const NAME: &'static str;
+    fn len(&self) -> usize;
+    fn capacity(&self) -> usize;
+    /// `values` must fit into the free space
+    fn write_back(&mut self, values: &[T]);
+    /// `values` must fit into the free space
+    fn write_front(&mut self, values: &[T]);
+    fn drop_front(&mut self, count: usize);
+    fn drop_back(&mut self, count: usize);
+}
+
+/// pushes `values` to the back of `ring` or to the front unless `back`.
+/// handles overflow according to `overflow` like
+/// [BoundedSliceRing::push_many_back_with](struct.BoundedSliceRing.html#method.push_many_back_with)
+/// and [push_many_front_with](struct.BoundedSliceRing.html#method.push_many_front_with).
+/// returns how many values were pushed.
+pub(crate) fn push_with<T, R: Bounded<T>>(ring: &mut R, values: &[T], overflow: Overflow, back: bool) -> usize {
+    let capacity = ring.capacity();
+    let free = capacity - ring.len();
+    if values.len() <= free {
+        if back { ring.write_back(values) } else { ring.write_front(values) }
+        return values.len();
+    }
+    match overflow {
+        Overflow::Reject => {
+            if back {
+                ring.write_back(&values[..free]);
+            } else {
+                ring.write_front(&values[values.len() - free..]);
+            }
+            free
+        }
+        Overflow::Overwrite => {
+            let count = cmp::min(capacity, values.len());
+            let excess = ring.len() + count - capacity;
+            if back {
+                ring.drop_front(excess);
+                ring.write_back(&values[values.len() - count..]);
+            } else {
+                ring.drop_back(excess);
+                ring.write_front(&values[..count]);
+            }
+            values.len()
+        }
+        Overflow::Panic => {
+            panic!("{} overflow: pushing {} values with only {} free",
+                   R::NAME, values.len(), free);
+        }
+    }
+}
+
+/// ring that holds at most a fixed number of elements.
+/// allocates once in the constructor and never again.
+/// useful in real-time threads where allocation is forbidden.
+///
+/// pushing more elements than fit is handled according to an
+/// [Overflow](enum.Overflow.html) policy.
+pub struct BoundedSliceRing<T> {
+    ring: SliceRingImpl<T>,
+    capacity: usize,
+    overflow: Overflow,
+}
+
+impl<T> BoundedSliceRing<T> {
+    /// creates an empty `BoundedSliceRing` that holds at most
+    /// `capacity` elements.
+    /// [SliceRing::push_many_back](trait.SliceRing.html#tymethod.push_many_back)
+    /// handles overflow according to `overflow`.
+    pub fn new(capacity: usize, overflow: Overflow) -> BoundedSliceRing<T> {
+        BoundedSliceRing {
+            ring: SliceRingImpl::with_capacity(capacity),
+            capacity,
+            overflow,
+        }
+    }
+
+    /// the maximum number of elements this ring holds
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// the overflow policy used by
+    /// [SliceRing::push_many_back](trait.SliceRing.html#tymethod.push_many_back)
+    #[inline]
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// returns the number of elements in the ring
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// returns `true` if the ring contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    /// returns `true` if no more elements fit into the ring
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity
+    }
+
+    /// returns how many more elements fit into the ring
+    #[inline]
+    pub fn free(&self) -> usize {
+        self.capacity - self.len()
+    }
+
+    /// removes and drops `count` elements from the front.
+    /// returns how many elements were dropped.
+    pub(crate) fn drop_front(&mut self, count: usize) -> usize {
+        self.ring.drop_front(count)
+    }
+
+    /// moves up to `output.len()` elements from the front into `output`.
+    /// returns how many elements were moved.
+    /// see [SliceRingImpl::pop_many_front](struct.SliceRingImpl.html#method.pop_many_front).
+    // `O(min(self.len(), output.len()))`
+    pub fn pop_many_front(&mut self, output: &mut [T]) -> usize {
+        self.ring.pop_many_front(output)
+    }
+
+    /// removes up to `count` elements from the front
+    /// and returns an iterator that yields them by value.
+    /// see [SliceRingImpl::drain_front](struct.SliceRingImpl.html#method.drain_front).
+    pub fn drain_front(&mut self, count: usize) -> DrainFront<'_, T> {
+        self.ring.drain_front(count)
+    }
+
+    /// removes and drops all elements
+    pub fn clear(&mut self) {
+        self.ring.clear();
+    }
+}
+
+impl<T: Clone> BoundedSliceRing<T> {
+    /// appends `values` to the back of this ring.
+    /// handles overflow according to `overflow`.
+    /// returns how many values were appended.
+    /// never allocates.
+    pub fn push_many_back_with(&mut self, values: &[T], overflow: Overflow) -> usize {
+        push_with(self, values, overflow, true)
+    }
+
+    /// prepends `values` to the front of this ring.
+    /// handles overflow according to `overflow` like
+    /// [push_many_back_with](#method.push_many_back_with)
+    /// with the ends swapped:
+    /// [Reject](enum.Overflow.html#variant.Reject) keeps the last values,
+    /// [Overwrite](enum.Overflow.html#variant.Overwrite) drops elements
+    /// from the back and keeps the first values.
+    /// returns how many values were prepended.
+    /// never allocates.
+    pub fn push_many_front_with(&mut self, values: &[T], overflow: Overflow) -> usize {
+        push_with(self, values, overflow, false)
+    }
+}
+
+impl<T: Clone> Bounded<T> for BoundedSliceRing<T> {
+    const NAME: &'static str = "bounded ring";
+
+    fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn write_back(&mut self, values: &[T]) {
+        self.ring.write_back(values);
+    }
+
+    fn write_front(&mut self, values: &[T]) {
+        self.ring.write_front(values);
+    }
+
+    fn drop_front(&mut self, count: usize) {
+        self.ring.drop_front(count);
+    }
+
+    fn drop_back(&mut self, count: usize) {
+        self.ring.drop_back(count);
+    }
+}
+
+impl<T: Clone> SliceRing<T> for BoundedSliceRing<T> {
+    // `O(input.len())`
+    fn push_many_back(&mut self, input: &[T]) {
+        let overflow = self.overflow;
+        self.push_many_back_with(input, overflow);
+    }
+
+    fn drop_many_front(&mut self, count: usize) -> usize {
+        self.drop_front(count)
+    }
+
+    fn read_many_front(&self, output: &mut [T]) -> usize {
+        self.ring.read_many_front(output)
+    }
+}
+
+impl<T: Clone> SliceRingExt<T> for BoundedSliceRing<T> {
+    // `O(input.len())`
+    fn try_push_many_back(&mut self, input: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(input.len())?;
+        self.ring.write_back(input);
+        Ok(())
+    }
+
+    // never allocates
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.free() < additional {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        Ok(())
+    }
+
+    // `O(1)`
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        self.ring.read_slices_front(count)
+    }
+
+    // `O(input.len())` plus the growth.
+    // growing past the capacity panics whatever the overflow policy
+    // since dropping or rejecting values would shift `offset`.
+    // never allocates.
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T)
+    {
+        let end = offset.checked_add(input.len()).expect("capacity overflow");
+        if self.capacity < end {
+            panic!("bounded ring overflow: combining up to {} values with a capacity of {}",
+                   end, self.capacity);
+        }
+        self.ring.combine_many_at(offset, input, f);
+    }
+
+    // `O(input.len())`
+    fn push_many_front(&mut self, input: &[T]) {
+        let overflow = self.overflow;
+        self.push_many_front_with(input, overflow);
+    }
+
+    fn drop_many_back(&mut self, count: usize) -> usize {
+        self.ring.drop_back(count)
+    }
+}
@@ -0,0 +1,193 @@
+//! byte rings as readers and writers
+//!
+//! `VecDeque<u8>` already implements `Read`, `BufRead` and `Write`
+//! in the standard library with the same semantics.
+
+use std::cmp;
+use std::io;
+use std::io::{BufRead, IoSlice, IoSliceMut, Read, Write};
+use std::ptr;
+
+use super::{wrap_index, wrapped_ranges, wrapped_slices_mut, SliceRingImpl};
+
+impl SliceRingImpl<u8> {
+    /// reads up to `max` bytes from `reader` straight into the free space
+    /// after the back. saves the copy through an intermediate buffer.
+    /// returns how many bytes were appended.
+    /// `0` means `reader` reached its end or `max` is `0`.
+    ///
+    /// reserves `max` bytes first.
+    /// so `max` sizes the allocation:
+    /// the ring grows until `max` more bytes fit
+    /// even if `reader` returns less.
+    /// pass the largest read you expect, not an upper bound.
+    /// free slots that never held a byte are zeroed once
+    /// since readers must get initialized memory.
+    /// if the free space wraps around the end of the underlying buffer
+    /// both parts are passed to a single `read_vectored`
+    /// so a reader that blocks isn't read twice.
+    /// retries if the read is interrupted.
+    ///
+    /// ```
+    /// use strider::SliceRingImpl;
+    ///
+    /// let mut ring = SliceRingImpl::new();
+    /// let mut input = &b"ABCDEFG"[..];
+    /// assert_eq!(ring.fill_from(&mut input, 4).unwrap(), 4);
+    /// assert_eq!(ring.fill_from(&mut input, 4).unwrap(), 3);
+    /// assert_eq!(ring.fill_from(&mut input, 4).unwrap(), 0);
+    /// assert_eq!(ring.read_slices_front(7).0, b"ABCDEFG");
+    /// ```
+    // `O(max)` plus the growth.
+    // `O(cap)` the first time after the ring grew
+    // or slots were uninitialized by `write_slices_back`
+    // or a `make_contiguous` that rotated the buffer.
+    pub fn fill_from<R: Read + ?Sized>(&mut self, reader: &mut R, max: usize) -> io::Result<usize> {
+        self.try_reserve(max).map_err(|_| io::ErrorKind::OutOfMemory)?;
+        let cap = self.cap();
+        let buf = self.ptr_mut();
+        let start = self.next_writable;
+        if self.initialized < cap {
+            // readers may not read from the buffer they get
+            // but must still get initialized memory
+            let (first, second) = wrapped_ranges(start, cap - self.len(), cap);
+            for free in &[first, second] {
+                let from = cmp::max(free.start, self.initialized);
+                if from < free.end {
+                    unsafe {
+                        ptr::write_bytes(buf.add(from), 0, free.end - from);
+                    }
+                }
+            }
+            self.initialized = cap;
+        }
+        let (front, back) = unsafe {
+            wrapped_slices_mut(buf, cap, start, max)
+        };
+        let read = loop {
+            let result = if back.is_empty() {
+                reader.read(front)
+            } else {
+                reader.read_vectored(&mut [IoSliceMut::new(front), IoSliceMut::new(back)])
+            };
+            match result {
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                result => break result?,
+            }
+        };
+        assert!(read <= max, "reader claims to have read more bytes than fit into the buffer");
+        self.next_writable = wrap_index(start.wrapping_add(read), cap);
+        Ok(read)
+    }
+
+    /// writes up to `max` bytes from the front to `writer`
+    /// without copying them into an intermediate buffer
+    /// and drops exactly the bytes that were written.
+    /// returns how many bytes were written.
+    /// that is less than `max` after a short write
+    /// or if the ring holds less bytes.
+    ///
+    /// if the bytes wrap around the end of the underlying buffer
+    /// both parts are passed to a single `write_vectored`.
+    /// retries if the write is interrupted.
+    ///
+    /// ```
+    /// use strider::{SliceRing, SliceRingImpl};
+    ///
+    /// let mut ring = SliceRingImpl::new();
+    /// ring.push_many_back(b"ABCDEFG");
+    /// let mut output = Vec::new();
+    /// assert_eq!(ring.write_to(&mut output, 4).unwrap(), 4);
+    /// assert_eq!(output, b"ABCD");
+    /// assert_eq!(ring.len(), 3);
+    /// ```
+    // `O(min(self.len(), max))`
+    pub fn write_to<W: Write + ?Sized>(&mut self, writer: &mut W, max: usize) -> io::Result<usize> {
+        let written = {
+            let (front, back) = self.read_slices_front(max);
+            let count = front.len() + back.len();
+            let written = loop {
+                let result = if back.is_empty() {
+                    writer.write(front)
+                } else {
+                    writer.write_vectored(&[IoSlice::new(front), IoSlice::new(back)])
+                };
+                match result {
+                    Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {}
+                    result => break result?,
+                }
+            };
+            assert!(written <= count, "writer claims to have written more bytes than it was given");
+            written
+        };
+        self.drop_front(written);
+        Ok(written)
+    }
+}
+
+/// appends the written bytes to the back.
+/// never writes less than the whole buffer.
+/// fails with `ErrorKind::OutOfMemory` if the ring can't grow.
+impl Write for SliceRingImpl<u8> {
+    // `O(buf.len())`
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.try_reserve(buf.len()).map_err(|_| io::ErrorKind::OutOfMemory)?;
+        self.write_back(buf);
+        Ok(buf.len())
+    }
+
+    /// reserves once for all buffers
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let len = bufs.iter().fold(0usize, |len, buf| len.saturating_add(buf.len()));
+        self.try_reserve(len).map_err(|_| io::ErrorKind::OutOfMemory)?;
+        for buf in bufs {
+            self.write_back(buf);
+        }
+        Ok(len)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write(buf).map(|_| ())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// removes the read bytes from the front.
+/// reads `0` bytes only if the ring is empty or `buf` is.
+impl Read for SliceRingImpl<u8> {
+    // `O(min(self.len(), buf.len()))`
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = {
+            let (front, back) = self.read_slices_front(buf.len());
+            buf[..front.len()].copy_from_slice(front);
+            buf[front.len()..front.len() + back.len()].copy_from_slice(back);
+            front.len() + back.len()
+        };
+        self.drop_front(count);
+        Ok(count)
+    }
+}
+
+/// `fill_buf` returns the first contiguous part of the ring
+/// without copying.
+/// that is all bytes unless they wrap around
+/// the end of the underlying buffer.
+impl BufRead for SliceRingImpl<u8> {
+    // `O(1)`
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        let len = self.len();
+        Ok(self.read_slices_front(len).0)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.drop_front(amt);
+    }
+}
@@ -30,7 +30,7 @@ you should be able to adapt it to your needs.
 
 ```no_run
 use std::io;
-use std::io::{Write, Read};
+use std::io::Write;
 
 extern crate strider;
 use strider::{SliceRing, SliceRingImpl};
@@ -40,16 +40,15 @@ fn main() {
     const STEP_SIZE: usize = 2;
 
     let mut ring = SliceRingImpl::<u8>::new();
-    let mut input_buffer: &mut [u8] = &mut [0; 20];
+    let mut stdin = io::stdin().lock();
     let mut window_buffer: &mut [u8] = &mut [0; WINDOW_SIZE];
 
     loop {
-        let input_count = io::stdin().read(input_buffer).unwrap();
+        // read up to 20 elements straight into the back of the ring
+        let input_count = ring.fill_from(&mut stdin, 20).unwrap();
         // leave the loop when we reach end of file
         if input_count == 0 { break; }
 
-        // add elements to the back of the ring
-        ring.push_many_back(&input_buffer[..input_count]);
         // read as long as we can read windows of length `WINDOW_SIZE`
         while WINDOW_SIZE <= ring.len() {
             ring.read_many_front(window_buffer);
@@ -61,6 +60,30 @@ fn main() {
 }
 ```
 
+## more
+
+[strider::Windower](struct.Windower.html) does the bookkeeping of the [example](#example) for you.
+it can also emit the trailing `K`
+by padding the last window or emitting a shorter one.
+
+[strider::SliceRingExt::read_many_front_windowed](trait.SliceRingExt.html#method.read_many_front_windowed)
+multiplies by a [window function](window/index.html) while reading.
+the `window` module needs the `std` feature.
+
+[strider::OverlapAddRing](struct.OverlapAddRing.html)
+goes the other way and sums processed windows back into a stream.
+
+`SliceRingImpl<u8>` implements `io::Write`, `io::Read` and `io::BufRead`
+like `VecDeque<u8>` does
+so it plugs directly into `io::copy`, decoders and parsers.
+[strider::SliceRingImpl::fill_from](struct.SliceRingImpl.html#method.fill_from)
+reads into the ring without an intermediate buffer.
+[strider::SliceRingImpl::write_to](struct.SliceRingImpl.html#method.write_to)
+writes from the ring the same way.
+
+[strider::SliceRingImpl::write_slices_back](struct.SliceRingImpl.html#method.write_slices_back)
+lets oscillators and decoders render straight into the ring.
+
 ## performance
 
 the trait [strider::SliceRing](trait.SliceRing.html) is implemented for
@@ -93,6 +116,10 @@ allocate and control.
 [strider::SliceRing::drop_many_front](trait.SliceRing.html#tymethod.read_many_drop)
 never allocates memory.
 
+[strider::SliceRingExt::read_slices_front](trait.SliceRingExt.html#tymethod.read_slices_front)
+never allocates or copies memory.
+it returns the values in place as two slices.
+
 [strider::SliceRing::push_many_back](trait.SliceRing.html#tymethod.push_many_back)
 reads from a buffer that you
 allocate and control.
@@ -101,13 +128,105 @@ when you push values and as a result there are more values in the ring than ever
 if you repeatedly read and drop after each push, as in the [example](#example) above,
 the number of values will stay below a certain value and it will never
 allocate memory after an initial 1 or 2 allocations.
+
+[strider::SliceRingExt::try_push_many_back](trait.SliceRingExt.html#tymethod.try_push_many_back)
+returns an error instead of aborting the process
+if that memory can't be allocated.
+[strider::SliceRingExt::try_reserve](trait.SliceRingExt.html#tymethod.try_reserve)
+allocates ahead of time.
+
+[strider::BoundedSliceRing](struct.BoundedSliceRing.html)
+allocates once when it is created and never again.
+it holds at most a fixed number of values.
+pushing more values either rejects the excess values,
+overwrites the oldest values or panics.
+use it in real-time threads where allocation is forbidden.
+
+[strider::ArraySliceRing](struct.ArraySliceRing.html)
+is stored inline in an array whose size is known at compile time.
+it needs no heap at all.
+
+[strider::MirroredSliceRing](struct.MirroredSliceRing.html)
+maps the same memory twice back-to-back.
+every window is one contiguous slice without any copying.
+enable the `mirrored` feature to use it on linux.
+
+[strider::SliceRingImpl::split](struct.SliceRingImpl.html#method.split)
+splits a ring into a producer and a consumer
+that can be used from two threads at the same time without locking.
+for example to push samples from an audio callback
+while another thread reads windows.
+
+[strider::SharedSliceRing](struct.SharedSliceRing.html)
+is shared between threads behind a lock.
+consumers can block until enough values for a window are there
+and producers can block until enough space is free.
+enable the `async` feature to `await` them from a task instead.
+
+## no_std
+
+strider works without the standard library.
+disable the default `std` feature to use it in `#![no_std]` crates:
+
+```toml
+[dependencies]
+strider = { version = "*", default-features = false }
+```
+
+[strider::SliceRingImpl](struct.SliceRingImpl.html)
+and the implementation for `VecDeque` then use the `alloc` crate.
 */
 
-use std::collections::VecDeque;
-use std::usize;
-use std::mem;
-use std::ptr;
-use std::cmp;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+extern crate core;
+extern crate alloc;
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+extern crate libc;
+#[cfg(loom)]
+extern crate loom;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::mem;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::cmp;
+use core::ops::{Index, IndexMut, Mul, Range};
+use core::slice;
+
+mod array;
+mod bounded;
+#[cfg(feature = "std")]
+mod io;
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+mod mirrored;
+mod overlap_add;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(target_has_atomic = "ptr")]
+mod spsc;
+mod windower;
+pub use array::ArraySliceRing;
+pub use bounded::{BoundedSliceRing, Overflow};
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+pub use mirrored::MirroredSliceRing;
+pub use overlap_add::OverlapAddRing;
+#[cfg(feature = "std")]
+pub use shared::SharedSliceRing;
+#[cfg(feature = "async")]
+pub use shared::{WaitForFree, WaitForLen};
+#[cfg(target_has_atomic = "ptr")]
+pub use spsc::{Consumer, Producer};
+pub use windower::{Tail, Windower};
+
+#[cfg(feature = "std")]
+pub mod window;
 
 /// ringbuffer operations on slices
 pub trait SliceRing<T> {
@@ -126,6 +245,114 @@ pub trait SliceRing<T> {
     fn read_many_front(&self, output: &mut [T]) -> usize;
 }
 
+/// more ringbuffer operations on slices.
+/// implemented for all rings in this crate.
+/// kept apart from [SliceRing](trait.SliceRing.html)
+/// so rings that only implement its three methods keep compiling.
+pub trait SliceRingExt<T>: SliceRing<T> {
+    /// appends `values` to the back of this ring.
+    /// returns an error and leaves this ring unchanged
+    /// if it can't make space for `values`.
+    /// never aborts the process when memory can't be allocated.
+    fn try_push_many_back(&mut self, values: &[T]) -> Result<(), TryReserveError>;
+    /// makes sure at least `additional` more elements can be pushed
+    /// to this ring without allocating.
+    /// returns an error and leaves this ring unchanged
+    /// if it can't make space for them.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
+    /// returns the first `count` elements present in this ring
+    /// as two slices without copying them.
+    /// the second slice is empty unless the elements wrap around
+    /// the end of the underlying buffer.
+    /// together the slices hold less than `count` elements
+    /// if there are less elements present in this ring.
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]);
+    /// copies the first `output.len()` elements present in this ring
+    /// into `output` and multiplies each by the coefficient at the same
+    /// index of `window`.
+    /// `window` is usually a table from the [window](window/index.html) module
+    /// which needs the `std` feature.
+    /// applies the window during the copy instead of in a second pass.
+    /// returns how many elements were copied.
+    /// panics if `window` and `output` differ in length.
+    // `O(min(self.len(), output.len()))`
+    fn read_many_front_windowed(&self, output: &mut [T], window: &[T]) -> usize
+        where T: Clone + Mul<Output = T>
+    {
+        assert_eq!(output.len(), window.len(),
+                   "output length must equal the window length");
+        let (front, back) = self.read_slices_front(output.len());
+        let values = front.iter().chain(back.iter());
+        for ((dst, value), coefficient) in output.iter_mut().zip(values).zip(window) {
+            *dst = value.clone() * coefficient.clone();
+        }
+        front.len() + back.len()
+    }
+    /// combines the elements of `input` with the elements starting
+    /// `offset` elements after the front of this ring
+    /// by calling `f(element, value)` for each pair.
+    /// for example `|a, b| *a += *b` mixes `input` into this ring.
+    ///
+    /// if `input` extends past the back this ring grows.
+    /// new elements start as `T::default()` before they are combined.
+    /// so do the elements between the back and `offset`.
+    /// panics like [push_many_back](trait.SliceRing.html#tymethod.push_many_back)
+    /// if this ring can't grow.
+    // `O(input.len())` plus the growth
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T), Self: Sized;
+    /// copies the `output.len()` elements starting `offset` elements
+    /// after the front of this ring into `output`.
+    /// returns how many elements were copied.
+    /// returns less than `output.len()` if there are less elements present
+    /// after `offset`.
+    // `O(min(self.len() - offset, output.len()))`
+    fn read_many_at(&self, offset: usize, output: &mut [T]) -> usize
+        where T: Clone
+    {
+        let (front, back) = self.read_slices_front(offset.saturating_add(output.len()));
+        // skip the first `offset` elements
+        let (front, back) = if offset <= front.len() {
+            (&front[offset..], back)
+        } else {
+            (&[][..], &back[cmp::min(offset - front.len(), back.len())..])
+        };
+        output[..front.len()].clone_from_slice(front);
+        output[front.len()..front.len() + back.len()].clone_from_slice(back);
+        front.len() + back.len()
+    }
+    /// prepends `values` to the front of this ring.
+    /// afterwards `values[0]` is the first element.
+    fn push_many_front(&mut self, values: &[T]);
+    /// removes `count` elements from the back of this ring.
+    /// returns how many elements were removed.
+    /// returns less than `count` if less elements are present
+    /// in this ring.
+    fn drop_many_back(&mut self, count: usize) -> usize;
+    /// copies the last `output.len()` elements present in this ring
+    /// into `output` in order.
+    /// returns how many elements were copied.
+    /// returns less than `output.len()` if there are less elements present
+    /// in this ring. they are copied to the start of `output`.
+    // `O(min(self.len(), output.len()))`
+    fn read_many_back(&self, output: &mut [T]) -> usize
+        where T: Clone
+    {
+        let (front, back) = self.read_slices_front(usize::MAX);
+        let count = cmp::min(front.len() + back.len(), output.len());
+        // skip all but the last `count` elements
+        let skip = front.len() + back.len() - count;
+        let (front, back) = if skip <= front.len() {
+            (&front[skip..], back)
+        } else {
+            (&[][..], &back[skip - front.len()..])
+        };
+        output[..front.len()].clone_from_slice(front);
+        output[front.len()..count].clone_from_slice(back);
+        count
+    }
+}
+
 impl<T: Clone> SliceRing<T> for VecDeque<T> {
     // `O(input.len())`
     fn push_many_back(&mut self, input: &[T]) {
@@ -137,7 +364,7 @@ impl<T: Clone> SliceRing<T> for VecDeque<T> {
     }
     // `O(count)`
     fn drop_many_front(&mut self, count: usize) -> usize {
-        let real_count = std::cmp::min(self.len(), count);
+        let real_count = cmp::min(self.len(), count);
         for _ in 0..real_count {
             self.pop_front();
         }
@@ -145,7 +372,7 @@ impl<T: Clone> SliceRing<T> for VecDeque<T> {
     }
     // `O(min(self.len(), output.len()))`
     fn read_many_front(&self, output: &mut [T]) -> usize {
-        let count = std::cmp::min(self.len(), output.len());
+        let count = cmp::min(self.len(), output.len());
         for i in 0..count {
             output[i] = self[i].clone();
         }
@@ -153,10 +380,94 @@ impl<T: Clone> SliceRing<T> for VecDeque<T> {
     }
 }
 
+impl<T: Clone> SliceRingExt<T> for VecDeque<T> {
+    // `O(input.len())`
+    fn try_push_many_back(&mut self, input: &[T]) -> Result<(), TryReserveError> {
+        SliceRingExt::try_reserve(self, input.len())?;
+        self.push_many_back(input);
+        Ok(())
+    }
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len().checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        // `VecDeque` doesn't tell us why it failed
+        // so we check for capacity overflow ourselves
+        let bytes = required.checked_mul(mem::size_of::<T>())
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if bytes > isize::MAX as usize {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+        VecDeque::try_reserve(self, additional)
+            .map_err(|_| TryReserveError::AllocError)
+    }
+    // `O(1)`
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        let (first, second) = self.as_slices();
+        if count <= first.len() {
+            (&first[..count], &[])
+        } else {
+            (first, &second[..cmp::min(count - first.len(), second.len())])
+        }
+    }
+    // `O(input.len())` plus the growth
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], mut f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T)
+    {
+        let end = offset.checked_add(input.len()).expect("capacity overflow");
+        if self.len() < end {
+            self.resize(end, T::default());
+        }
+        for (element, value) in self.range_mut(offset..end).zip(input) {
+            f(element, value);
+        }
+    }
+    // `O(input.len())`
+    fn push_many_front(&mut self, input: &[T]) {
+        for value in input.iter().rev() {
+            self.push_front(value.clone());
+        }
+    }
+    // `O(count)`
+    fn drop_many_back(&mut self, count: usize) -> usize {
+        let real_count = cmp::min(self.len(), count);
+        let len = self.len();
+        self.truncate(len - real_count);
+        real_count
+    }
+}
+
+/// the error returned when a ring can't make space for more elements.
+/// the ring is left unchanged.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// the required capacity exceeds the maximum capacity of the ring.
+    /// for most types the maximum is reached when the underlying buffer
+    /// would exceed `isize::MAX` bytes.
+    CapacityOverflow,
+    /// the memory allocator failed to allocate the underlying buffer.
+    AllocError,
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TryReserveError::CapacityOverflow =>
+                f.write_str("capacity overflow"),
+            TryReserveError::AllocError =>
+                f.write_str("memory allocation failed"),
+        }
+    }
+}
+
+impl Error for TryReserveError {}
+
 const INITIAL_CAPACITY: usize = 7; // 2^3 - 1
 const MINIMUM_CAPACITY: usize = 1; // 2 - 1
-// TODO don't know if this is correct
-const MAXIMUM_ZST_CAPACITY: usize = usize::MAX;
+/// zero sized types don't need a buffer.
+/// a ring of them is just a counter.
+/// this is the largest power of two so the same index masking
+/// works for them.
+const MAXIMUM_ZST_CAPACITY: usize = 1 << (usize::BITS - 1);
 
 /// readable area starts at `first_readable` and goes until
 /// `next_writable`.
@@ -171,16 +482,63 @@ const MAXIMUM_ZST_CAPACITY: usize = usize::MAX;
 ///  R             W
 /// [o o o o o o o . . . .]
 /// ```
+///
+/// the ring owns its elements:
+/// every element that is pushed is dropped exactly once.
+/// either when it is dropped from the front,
+/// when the ring is cleared or when the ring itself is dropped.
 pub struct SliceRingImpl<T> {
     /// index into `buf` of the first element that could be read.
     /// only gets incremented, never decremented.
     /// wraps around.
-    pub first_readable: usize,
+    first_readable: usize,
     /// index into `buf` where the next element could we written.
     /// only gets incremented, never decremented.
-    /// wraps around at `buf.cap()`.
-    pub next_writable: usize,
-    pub buf: Vec<T>,
+    /// wraps around at `buf.len()`.
+    next_writable: usize,
+    /// `buf.len()` is always a power of two and equal to `cap()`.
+    /// only the slots between `first_readable` and `next_writable`
+    /// are initialized.
+    /// growing is done through `Vec::resize_with` which
+    /// keeps the contents of all slots in place.
+    buf: Vec<MaybeUninit<T>>,
+    /// how often `make_contiguous` had to move elements
+    rotations: usize,
+    /// the slots of `buf` below this index hold initialized bytes
+    /// even if they are free.
+    /// lets `fill_from` zero each free slot just once.
+    /// lowered whenever slots might lose their bytes.
+    initialized: usize,
+}
+
+/// drops the elements of a slice when it goes out of scope.
+/// used to drop the second half of a wrapped range
+/// even if a destructor in the first half panics.
+struct Dropper<T>(*mut [T]);
+
+impl<T> Drop for Dropper<T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.0);
+        }
+    }
+}
+
+/// advances `next_writable` by the number of elements written so far
+/// when it goes out of scope.
+/// this way elements written before a panicking `clone`
+/// are still owned by the ring and get dropped exactly once.
+struct WriteGuard<'a> {
+    next_writable: &'a mut usize,
+    cap: usize,
+    written: usize,
+}
+
+impl<'a> Drop for WriteGuard<'a> {
+    fn drop(&mut self) {
+        *self.next_writable = wrap_index(
+            self.next_writable.wrapping_add(self.written), self.cap);
+    }
 }
 
 /// Calculate the number of elements left to be read in the buffer
@@ -200,9 +558,182 @@ fn wrap_index(index: usize, size: usize) -> usize {
     index & max_index
 }
 
+/// returns the ranges of slots that hold the `count` elements
+/// starting at slot `start` of a buffer with `size` slots.
+/// the second range is empty unless those elements wrap around.
 #[inline]
-fn next_power_of_two(x: usize) -> usize {
-    (if x.is_power_of_two() { x + 1 } else { x }).next_power_of_two()
+fn wrapped_ranges(start: usize, count: usize, size: usize) -> (Range<usize>, Range<usize>) {
+    let first_len = cmp::min(count, size - start);
+    (start..start + first_len, 0..count - first_len)
+}
+
+/// returns the `count` elements in the slots of `buf` starting at `start`
+/// as two slices.
+/// `buf` has `size` slots.
+#[inline]
+unsafe fn wrapped_slices<'a, T>(
+    buf: *const T, size: usize, start: usize, count: usize
+) -> (&'a [T], &'a [T]) {
+    let (front, back) = wrapped_ranges(start, count, size);
+    (slice::from_raw_parts(buf.add(front.start), front.len()),
+     slice::from_raw_parts(buf.add(back.start), back.len()))
+}
+
+/// returns the `count` elements in the slots of `buf` starting at `start`
+/// as two mutable slices.
+/// `buf` has `size` slots.
+#[inline]
+unsafe fn wrapped_slices_mut<'a, T>(
+    buf: *mut T, size: usize, start: usize, count: usize
+) -> (&'a mut [T], &'a mut [T]) {
+    let (front, back) = wrapped_ranges(start, count, size);
+    (slice::from_raw_parts_mut(buf.add(front.start), front.len()),
+     slice::from_raw_parts_mut(buf.add(back.start), back.len()))
+}
+
+/// writes `count` default values into the slots of `buf` starting at
+/// `*next_writable` and advances `*next_writable` past them.
+/// `buf` has `size` slots and there must be enough free slots.
+#[inline]
+unsafe fn write_default_wrapping<T: Default>(
+    buf: *mut T, size: usize, next_writable: &mut usize, count: usize
+) {
+    for _ in 0..count {
+        ptr::write(buf.add(*next_writable), T::default());
+        // advance one at a time so a panicking `default`
+        // leaves every written element owned by the ring
+        *next_writable = wrap_index(next_writable.wrapping_add(1), size);
+    }
+}
+
+/// combines `input` with the `input.len()` elements in the slots of `buf`
+/// starting at `start`.
+/// `buf` has `size` slots.
+#[inline]
+unsafe fn combine_wrapping<T, F: FnMut(&mut T, &T)>(
+    buf: *mut T, size: usize, start: usize, input: &[T], mut f: F
+) {
+    let (front, back) = wrapped_slices_mut(buf, size, start, input.len());
+    for (element, value) in front.iter_mut().chain(back.iter_mut()).zip(input) {
+        f(element, value);
+    }
+}
+
+/// clones `input` into the slots of `buf` starting at `*next_writable`
+/// and advances `*next_writable` past them.
+/// `buf` has `size` slots and there must be enough free slots.
+///
+/// if a `clone` panics `*next_writable` is still advanced past
+/// the elements written so far.
+#[inline]
+unsafe fn write_wrapping<T: Clone>(
+    buf: *mut T, size: usize, next_writable: &mut usize, input: &[T]
+) {
+    let first_writable = *next_writable;
+    let mut guard = WriteGuard {
+        next_writable,
+        cap: size,
+        written: 0,
+    };
+    for i in 0..input.len() {
+        // Unsafe code so this can be optimised to a memcpy (or something
+        // similarly fast) when T is Copy. LLVM is easily confused, so any
+        // extra operations during the loop can prevent this optimisation.
+        // TODO benchmark a T (struct) that is Copy
+        // vs a T (struct) that is Clone
+        // TODO maybe replace by two loops that
+        // each copy consecutive elements
+        let dst_index = wrap_index(first_writable.wrapping_add(i), size);
+        let src = input.get_unchecked(i).clone();
+        ptr::write(buf.add(dst_index), src);
+        guard.written += 1;
+    }
+}
+
+/// clones `input` into the slots of `buf` that end at `*first_readable`
+/// and moves `*first_readable` back to the first of them.
+/// `buf` has `size` slots and there must be enough free slots.
+///
+/// writes from the last value to the first and moves `*first_readable`
+/// after every value so a panicking `clone` leaves
+/// every written element owned by the ring.
+#[inline]
+unsafe fn write_front_wrapping<T: Clone>(
+    buf: *mut T, size: usize, first_readable: &mut usize, input: &[T]
+) {
+    for value in input.iter().rev() {
+        let index = wrap_index(first_readable.wrapping_sub(1), size);
+        ptr::write(buf.add(index), value.clone());
+        *first_readable = index;
+    }
+}
+
+/// clones the elements in the slots of `buf` starting at `first_readable`
+/// into `output`.
+/// `buf` has `size` slots and must hold at least `output.len()` elements.
+#[inline]
+unsafe fn read_wrapping<T: Clone>(
+    buf: *const T, size: usize, first_readable: usize, output: &mut [T]
+) {
+    for i in 0..output.len() {
+        // Unsafe code so this can be optimised to a memcpy (or something
+        // similarly fast) when T is Copy. LLVM is easily confused, so any
+        // extra operations during the loop can prevent this optimisation.
+        let dst = output.get_unchecked_mut(i);
+        let src_index = wrap_index(first_readable.wrapping_add(i), size);
+        let src = (*buf.add(src_index)).clone();
+        *dst = src;
+    }
+}
+
+/// moves `output.len()` elements out of the slots of `buf`
+/// starting at `first_readable` into `output`
+/// and advances `first_readable` past them.
+/// `buf` has `size` slots and must hold at least `output.len()` elements.
+/// `first_readable` is advanced before each element is moved
+/// so a panicking destructor of a value in `output`
+/// can't lead to a double drop.
+#[inline]
+unsafe fn pop_wrapping<T>(
+    buf: *const T, size: usize, first_readable: &mut usize, output: &mut [T]
+) {
+    for dst in output {
+        let src = *first_readable;
+        *first_readable = wrap_index(src.wrapping_add(1), size);
+        *dst = ptr::read(buf.add(src));
+    }
+}
+
+/// drops the `count` elements in the slots of `buf` starting at `start`.
+/// `buf` has `size` slots.
+/// the slots must no longer be considered occupied before calling this
+/// so a panicking destructor can't lead to a double drop.
+#[inline]
+unsafe fn drop_wrapping<T>(buf: *mut T, size: usize, start: usize, count: usize) {
+    let (front, back) = wrapped_ranges(start, count, size);
+    let _back = Dropper(ptr::slice_from_raw_parts_mut(buf.add(back.start), back.len()));
+    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(buf.add(front.start), front.len()));
+}
+
+/// returns the number of slots the underlying buffer needs
+/// to hold `required` elements of type `T`.
+/// returns `None` if that is more than the maximum capacity.
+#[inline]
+fn cap_for<T>(required: usize) -> Option<usize> {
+    if mem::size_of::<T>() == 0 {
+        return if required < MAXIMUM_ZST_CAPACITY {
+            Some(MAXIMUM_ZST_CAPACITY)
+        } else {
+            None
+        };
+    }
+    // +1 since the ringbuffer always leaves one space empty
+    let cap = cmp::max(required.checked_add(1)?, MINIMUM_CAPACITY + 1)
+        .checked_next_power_of_two()?;
+    if cap.checked_mul(mem::size_of::<T>())? > isize::MAX as usize {
+        return None;
+    }
+    Some(cap)
 }
 
 /// ringbuffer focused on and optimized for operating on slices of values:
@@ -217,15 +748,20 @@ impl<T> SliceRingImpl<T> {
     }
 
     /// creates an empty `SliceRingImpl` with space for at least `n` elements.
+    /// panics if `n` exceeds the maximum capacity.
     pub fn with_capacity(n: usize) -> SliceRingImpl<T> {
-        // +1 since the ringbuffer always leaves one space empty
-        let cap = cmp::max(n + 1, MINIMUM_CAPACITY + 1).next_power_of_two();
-        assert!(cap > n, "capacity overflow");
+        let cap = cap_for::<T>(n).expect("capacity overflow");
 
+        let mut buf = Vec::new();
+        if mem::size_of::<T>() != 0 {
+            buf.resize_with(cap, MaybeUninit::uninit);
+        }
         SliceRingImpl {
             first_readable: 0,
             next_writable: 0,
-            buf: Vec::with_capacity(cap),
+            buf,
+            rotations: 0,
+            initialized: 0,
         }
     }
 
@@ -235,7 +771,7 @@ impl<T> SliceRingImpl<T> {
             // For zero sized types, we are always at maximum capacity
             MAXIMUM_ZST_CAPACITY
         } else {
-            self.buf.capacity()
+            self.buf.len()
         }
     }
 
@@ -244,6 +780,18 @@ impl<T> SliceRingImpl<T> {
         self.cap() - 1
     }
 
+    /// pointer to the first slot of the underlying buffer
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.buf.as_ptr() as *const T
+    }
+
+    /// mutable pointer to the first slot of the underlying buffer
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.buf.as_mut_ptr() as *mut T
+    }
+
     #[inline]
     pub fn is_continuous(&self) -> bool {
         self.first_readable <= self.next_writable
@@ -255,15 +803,153 @@ impl<T> SliceRingImpl<T> {
         count(self.first_readable, self.next_writable, self.cap())
     }
 
+    /// returns `true` if the `SliceRingImpl` contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.first_readable == self.next_writable
+    }
+
+    /// returns the first `count` elements as two slices without copying them.
+    /// the second slice is empty unless the elements wrap around
+    /// the end of the underlying buffer.
+    /// together the slices hold less than `count` elements
+    /// if there are less elements present.
+    // `O(1)`
+    #[inline]
+    pub fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        let count = cmp::min(self.len(), count);
+        unsafe {
+            wrapped_slices(self.ptr(), self.cap(), self.first_readable, count)
+        }
+    }
+
+    /// returns a reference to the element `index` elements after the front.
+    /// returns `None` if there is no such element.
+    // `O(1)`
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            unsafe { Some(&*self.ptr().add(self.wrap_add(self.first_readable, index))) }
+        } else {
+            None
+        }
+    }
+
+    /// returns a mutable reference to the element `index` elements
+    /// after the front.
+    /// returns `None` if there is no such element.
+    // `O(1)`
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len() {
+            let physical = self.wrap_add(self.first_readable, index);
+            unsafe { Some(&mut *self.ptr_mut().add(physical)) }
+        } else {
+            None
+        }
+    }
+
+    /// removes and drops `count` elements from the front.
+    /// returns how many elements were dropped.
+    fn drop_front(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len(), count);
+        let start = self.first_readable;
+        self.first_readable = self.wrap_add(self.first_readable, count);
+        let cap = self.cap();
+        unsafe {
+            drop_wrapping(self.ptr_mut(), cap, start, count);
+        }
+        count
+    }
+
+    /// appends `value` to the back
+    fn push_back(&mut self, value: T) {
+        if let Err(err) = SliceRingImpl::try_reserve(self, 1) {
+            panic!("{}", err);
+        }
+        let next_writable = self.next_writable;
+        unsafe {
+            ptr::write(self.ptr_mut().add(next_writable), value);
+        }
+        self.next_writable = self.wrap_add(next_writable, 1);
+    }
+
+    /// moves up to `output.len()` elements from the front into `output`.
+    /// the values `output` held before are dropped.
+    /// returns how many elements were moved.
+    /// unlike `read_many_front` followed by `drop_many_front`
+    /// it doesn't clone so `T` doesn't need to be `Clone`.
+    // `O(min(self.len(), output.len()))`
+    pub fn pop_many_front(&mut self, output: &mut [T]) -> usize {
+        let count = cmp::min(self.len(), output.len());
+        let cap = self.cap();
+        let buf = self.ptr();
+        unsafe {
+            pop_wrapping(buf, cap, &mut self.first_readable, &mut output[..count]);
+        }
+        count
+    }
+
+    /// removes up to `count` elements from the front
+    /// and returns an iterator that yields them by value.
+    /// the elements the iterator didn't yield are dropped
+    /// when it is dropped.
+    /// if the iterator is leaked they stay in the ring.
+    ///
+    /// ```
+    /// use strider::{SliceRing, SliceRingImpl};
+    ///
+    /// let mut ring = SliceRingImpl::new();
+    /// ring.push_many_back(&[String::from("a"), String::from("b"), String::from("c")]);
+    /// let drained: Vec<String> = ring.drain_front(2).collect();
+    /// assert_eq!(drained, ["a", "b"]);
+    /// assert_eq!(ring.len(), 1);
+    /// ```
+    pub fn drain_front(&mut self, count: usize) -> DrainFront<'_, T> {
+        let remaining = cmp::min(self.len(), count);
+        DrainFront {
+            ring: self,
+            remaining,
+        }
+    }
+
+    /// removes and drops `count` elements from the back.
+    /// returns how many elements were dropped.
+    fn drop_back(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len(), count);
+        self.next_writable = self.wrap_sub(self.next_writable, count);
+        let start = self.next_writable;
+        let cap = self.cap();
+        unsafe {
+            drop_wrapping(self.ptr_mut(), cap, start, count);
+        }
+        count
+    }
+
+    /// removes and drops all elements
+    pub fn clear(&mut self) {
+        let len = self.len();
+        self.drop_front(len);
+        self.first_readable = 0;
+        self.next_writable = 0;
+    }
+
     /// returns the index into the underlying buffer
     /// for a given logical element
     /// index + addend
     #[inline]
     pub fn wrap_add(&self, index: usize, addend: usize) -> usize {
-        // wrapping_add is a method of std::usize
+        // wrapping_add is a method of usize
         wrap_index(index.wrapping_add(addend), self.cap())
     }
 
+    /// returns the index into the underlying buffer
+    /// `subtrahend` elements before `index`
+    #[inline]
+    fn wrap_sub(&self, index: usize, subtrahend: usize) -> usize {
+        wrap_index(index.wrapping_sub(subtrahend), self.cap())
+    }
+
     /// Copies a contiguous block of memory len long from src to dst
     /// we can use this if we own the data and move it around
     /// instead of copying it.
@@ -275,11 +961,211 @@ impl<T> SliceRingImpl<T> {
                       self.cap());
         debug_assert!(src + len <= self.cap(), "dst={} src={} len={} cap={}", dst, src, len,
                       self.cap());
+        let buf = self.ptr_mut();
+        ptr::copy_nonoverlapping(buf.add(src), buf.add(dst), len);
+    }
+
+    /// Copies a contiguous block of memory len long from src to dst.
+    /// like `copy_nonoverlapping` but the blocks may overlap.
+    #[inline]
+    unsafe fn copy(&mut self, src: usize, dst: usize, len: usize) {
+        debug_assert!(dst + len <= self.cap(), "dst={} src={} len={} cap={}", dst, src, len,
+                      self.cap());
+        debug_assert!(src + len <= self.cap(), "dst={} src={} len={} cap={}", dst, src, len,
+                      self.cap());
+        let buf = self.ptr_mut();
+        ptr::copy(buf.add(src), buf.add(dst), len);
+    }
+
+    /// moves the elements so they don't wrap around the end
+    /// of the underlying buffer.
+    /// returns all elements as one mutable slice.
+    /// does nothing if the elements don't wrap around.
+    /// never allocates.
+    // `O(1)` if the elements don't wrap around.
+    // `O(cap)` otherwise.
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        let len = self.len();
+        let cap = self.cap();
+        if cap - self.first_readable < len {
+            self.rotations += 1;
+            // R = first_readable
+            // W = next_writable
+            // h = head section from R to the end of the buffer
+            // t = tail section from the start of the buffer to W
+            // . = free
+            let head_len = cap - self.first_readable;
+            let tail_len = self.next_writable;
+            let free = self.first_readable - self.next_writable;
+            unsafe {
+                if mem::size_of::<T>() == 0 {
+                    // zero sized types don't need to be moved
+                    self.first_readable = 0;
+                } else if head_len <= free {
+                    // head fits into the free space:
+                    //      W     R           R         W
+                    // [t t . . . h h h ] -> [h h h t t . . . ]
+                    self.copy(0, head_len, tail_len);
+                    let first_readable = self.first_readable;
+                    self.copy_nonoverlapping(first_readable, 0, head_len);
+                    self.first_readable = 0;
+                } else if tail_len <= free {
+                    // tail fits into the free space:
+                    //    W   R               W   R
+                    // [t . . h h h h h ] -> [. . h h h h h t ]
+                    let first_readable = self.first_readable - tail_len;
+                    self.copy(self.first_readable, first_readable, head_len);
+                    self.copy_nonoverlapping(0, cap - tail_len, tail_len);
+                    self.first_readable = first_readable;
+                } else {
+                    // neither fits. rotate the whole buffer in place:
+                    //        W R             R             W
+                    // [t t t . h h h h ] -> [h h h h t t t . ]
+                    let first_readable = self.first_readable;
+                    self.buf.rotate_left(first_readable);
+                    self.first_readable = 0;
+                    // free slots were moved too
+                    self.initialized = 0;
+                }
+            }
+            self.next_writable = self.wrap_add(self.first_readable, len);
+        }
+        let first_readable = self.first_readable;
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr_mut().add(first_readable), len)
+        }
+    }
+
+    /// returns the first `count` elements as one mutable slice.
+    /// moves the elements with `make_contiguous` only
+    /// if those `count` elements wrap around the end of the underlying buffer.
+    /// the returned slice is shorter than `count`
+    /// if there are less elements present.
+    /// never allocates.
+    ///
+    /// useful for windowed FFT where each window is needed as one slice.
+    /// `rotations` tells how often elements had to be moved.
+    // `O(1)` if the elements don't wrap around.
+    // `O(cap)` otherwise.
+    pub fn contiguous_front_mut(&mut self, count: usize) -> &mut [T] {
+        let count = cmp::min(self.len(), count);
+        if self.cap() - self.first_readable < count {
+            self.make_contiguous();
+        }
+        let first_readable = self.first_readable;
+        unsafe {
+            slice::from_raw_parts_mut(self.ptr_mut().add(first_readable), count)
+        }
+    }
+
+    /// returns how often `make_contiguous` and `contiguous_front_mut`
+    /// had to move elements.
+    /// if this grows quickly a larger capacity may help
+    /// since windows then straddle the end of the buffer less often.
+    #[inline]
+    pub fn rotations(&self) -> usize {
+        self.rotations
+    }
+
+    /// reserves `count` slots after the back and returns them
+    /// as two slices of uninitialized memory.
+    /// the second slice is empty unless the slots wrap around
+    /// the end of the underlying buffer.
+    /// write into them in order and call [commit_back](#method.commit_back)
+    /// to append the written elements.
+    /// lets a producer render straight into the ring
+    /// instead of into a temporary that is then pushed.
+    /// panics if the ring can't grow.
+    ///
+    /// ```
+    /// use strider::{SliceRing, SliceRingImpl};
+    ///
+    /// let mut ring = SliceRingImpl::<f32>::new();
+    /// let (front, back) = ring.write_slices_back(4);
+    /// for (i, slot) in front.iter_mut().chain(back).enumerate() {
+    ///     slot.write(i as f32 * 0.5);
+    /// }
+    /// unsafe { ring.commit_back(4) };
+    ///
+    /// let mut output = [0.; 4];
+    /// ring.read_many_front(&mut output);
+    /// assert_eq!(output, [0., 0.5, 1., 1.5]);
+    /// ```
+    pub fn write_slices_back(&mut self, count: usize)
+        -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+        if let Err(err) = SliceRingImpl::try_reserve(self, count) {
+            panic!("{}", err);
+        }
+        let cap = self.cap();
+        let next_writable = self.next_writable;
+        // the slots might be left uninitialized
+        self.initialized = if next_writable + count <= cap {
+            cmp::min(self.initialized, next_writable)
+        } else {
+            0
+        };
+        unsafe {
+            wrapped_slices_mut(self.buf.as_mut_ptr(), cap, next_writable, count)
+        }
+    }
+
+    /// appends the `count` elements written into the slots
+    /// returned by [write_slices_back](#method.write_slices_back).
+    /// panics if `count` exceeds the free space.
+    ///
+    /// # Safety
+    ///
+    /// the first `count` slots after the back must be initialized.
+    /// the first slice returned by `write_slices_back` comes first.
+    // `O(1)`
+    pub unsafe fn commit_back(&mut self, count: usize) {
+        let free = self.capacity() - self.len();
+        assert!(count <= free, "committing {} elements with only {} free", count, free);
+        self.next_writable = self.wrap_add(self.next_writable, count);
+    }
+
+    /// makes sure at least `additional` more elements can be pushed
+    /// without allocating.
+    /// returns an error and leaves the ring unchanged
+    /// if it can't make space for them.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len().checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if self.capacity() < required {
+            let new_cap = cap_for::<T>(required)
+                .ok_or(TryReserveError::CapacityOverflow)?;
+            self.grow(new_cap)?;
+        }
+        Ok(())
+    }
+
+    /// grows the underlying buffer to `new_cap` slots
+    /// and moves the elements so their order is preserved.
+    /// `new_cap` must be a power of two and at least twice `cap()`.
+    fn grow(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        debug_assert!(new_cap.is_power_of_two());
+        debug_assert!(new_cap >= 2 * self.cap());
+        let old_cap = self.cap();
+        // allocate first so `resize_with` can't fail
+        self.buf.try_reserve_exact(new_cap - old_cap)
+            .map_err(|_| TryReserveError::AllocError)?;
+        self.buf.resize_with(new_cap, MaybeUninit::uninit);
+        unsafe {
+            self.handle_cap_increase(old_cap);
+        }
+        Ok(())
     }
 
     /// this is the most complex part
     /// Frobs the head and tail sections around to handle the fact that we
-    /// just reallocated. Unsafe because it trusts old_cap.
+    /// just reallocated.
+    /// only the layout matters, not at which end the ring grows.
+    /// so this serves pushes to the front and to the back alike.
+    ///
+    /// # Safety
+    ///
+    /// `old_cap` must be the value of `cap()` before the reallocation
+    /// and the new `cap()` must be at least twice `old_cap`.
     #[inline]
     pub unsafe fn handle_cap_increase(&mut self, old_cap: usize) {
         // move the shortest contiguous section of the ring buffer
@@ -313,7 +1199,6 @@ impl<T> SliceRingImpl<T> {
         // after handle_cap_increase:
         //        R             W
         // [. . . o o o o o c c . . . . . . ]
-        // TODO test this
         if self.next_writable < old_cap - self.first_readable {
             let next_writable = self.next_writable;
             let copy_src = 0;
@@ -330,7 +1215,6 @@ impl<T> SliceRingImpl<T> {
             return
         }
 
-        // TODO test this
         // shortest section at tail:
         // before cap increase:
         //            W R
@@ -356,67 +1240,283 @@ impl<T> SliceRingImpl<T> {
     }
 }
 
-// TODO test with zero sized types and max length
+impl<T> Index<usize> for SliceRingImpl<T> {
+    type Output = T;
+
+    /// panics if there is no element `index` elements after the front
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        let len = self.len();
+        match self.get(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for SliceRingImpl<T> {
+    /// panics if there is no element `index` elements after the front
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!("index out of bounds: the len is {} but the index is {}", len, index),
+        }
+    }
+}
+
+/// iterator that moves elements out of the front of a
+/// [SliceRingImpl](struct.SliceRingImpl.html).
+/// returned by [drain_front](struct.SliceRingImpl.html#method.drain_front).
+pub struct DrainFront<'a, T: 'a> {
+    ring: &'a mut SliceRingImpl<T>,
+    /// how many elements at the front still belong to the iterator
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for DrainFront<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let ring = &mut *self.ring;
+        let src = ring.first_readable;
+        ring.first_readable = ring.wrap_add(src, 1);
+        unsafe { Some(ptr::read(ring.ptr().add(src))) }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DrainFront<'a, T> {}
+
+impl<'a, T> Drop for DrainFront<'a, T> {
+    fn drop(&mut self) {
+        let remaining = mem::replace(&mut self.remaining, 0);
+        self.ring.drop_front(remaining);
+    }
+}
+
+impl<T: Default> SliceRingImpl<T> {
+    /// appends `count` default values to the back
+    fn push_default_back(&mut self, count: usize) {
+        if let Err(err) = SliceRingImpl::try_reserve(self, count) {
+            panic!("{}", err);
+        }
+        let cap = self.cap();
+        let buf = self.ptr_mut();
+        unsafe {
+            write_default_wrapping(buf, cap, &mut self.next_writable, count);
+        }
+    }
+}
+
+impl<T> Drop for SliceRingImpl<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for SliceRingImpl<T> {
+    fn default() -> SliceRingImpl<T> {
+        SliceRingImpl::new()
+    }
+}
+
+impl<T: Clone> Clone for SliceRingImpl<T> {
+    /// clones the elements into a ring that has just enough capacity
+    fn clone(&self) -> SliceRingImpl<T> {
+        let mut ring = SliceRingImpl::with_capacity(self.len());
+        let (front, back) = self.read_slices_front(self.len());
+        ring.write_back(front);
+        ring.write_back(back);
+        ring
+    }
+
+    /// reuses the allocation of `self` if it is large enough
+    fn clone_from(&mut self, source: &SliceRingImpl<T>) {
+        self.clear();
+        let (front, back) = source.read_slices_front(source.len());
+        if let Err(err) = SliceRingImpl::try_reserve(self, source.len()) {
+            panic!("{}", err);
+        }
+        self.write_back(front);
+        self.write_back(back);
+    }
+}
+
+/// shows the elements from front to back
+impl<T: fmt::Debug> fmt::Debug for SliceRingImpl<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (front, back) = self.read_slices_front(self.len());
+        f.debug_list().entries(front.iter().chain(back)).finish()
+    }
+}
+
+/// compares the elements in order
+/// regardless of where they wrap around the end of the underlying buffer
+/// and of the capacity
+impl<T: PartialEq> PartialEq for SliceRingImpl<T> {
+    fn eq(&self, other: &SliceRingImpl<T>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        let (front, back) = self.read_slices_front(self.len());
+        let (other_front, other_back) = other.read_slices_front(other.len());
+        front.iter().chain(back).eq(other_front.iter().chain(other_back))
+    }
+}
+
+impl<T: Eq> Eq for SliceRingImpl<T> {}
+
+/// hashes the length and the elements in order
+/// like `VecDeque` does
+impl<T: Hash> Hash for SliceRingImpl<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        let (front, back) = self.read_slices_front(self.len());
+        for element in front.iter().chain(back) {
+            element.hash(state);
+        }
+    }
+}
+
+/// appends the elements to the back.
+/// moves them so `T` doesn't need to be `Clone`.
+impl<T> Extend<T> for SliceRingImpl<T> {
+    // `O(n)` where n is the number of elements
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        if let Err(err) = SliceRingImpl::try_reserve(self, iter.size_hint().0) {
+            panic!("{}", err);
+        }
+        for element in iter {
+            self.push_back(element);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for SliceRingImpl<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<T> FromIterator<T> for SliceRingImpl<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> SliceRingImpl<T> {
+        let mut ring = SliceRingImpl::new();
+        ring.extend(iter);
+        ring
+    }
+}
+
+impl<T: Clone> SliceRingImpl<T> {
+    /// clones `input` into the free space after the back.
+    /// there must be enough free space.
+    fn write_back(&mut self, input: &[T]) {
+        debug_assert!(self.len() + input.len() <= self.capacity());
+        let cap = self.cap();
+        let buf = self.ptr_mut();
+        unsafe {
+            write_wrapping(buf, cap, &mut self.next_writable, input);
+        }
+    }
+
+    /// clones `input` into the free space before the front.
+    /// there must be enough free space.
+    fn write_front(&mut self, input: &[T]) {
+        debug_assert!(self.len() + input.len() <= self.capacity());
+        let cap = self.cap();
+        let buf = self.ptr_mut();
+        unsafe {
+            write_front_wrapping(buf, cap, &mut self.first_readable, input);
+        }
+    }
+}
 
 impl<T: Clone> SliceRing<T> for SliceRingImpl<T> {
     // `O(input.len())`
     fn push_many_back(&mut self, input: &[T]) {
-        // make enough space
-        let additional = input.len();
-        let required = self.len() + additional;
-        if self.capacity() < required {
-            let old_cap = self.cap();
-            self.buf.reserve(next_power_of_two(required));
-            unsafe {
-                self.handle_cap_increase(old_cap);
-            }
+        if let Err(err) = self.try_push_many_back(input) {
+            panic!("{}", err);
         }
-
-        for i in 0..additional {
-            // Unsafe code so this can be optimised to a memcpy (or something
-            // similarly fast) when T is Copy. LLVM is easily confused, so any
-            // extra operations during the loop can prevent this optimisation.
-            // TODO benchmark a T (struct) that is Copy
-            // vs a T (struct) that is Clone
-            // TODO maybe replace by two loops that
-            // each copy consecutive elements
-            unsafe {
-                let dst_index = self.wrap_add(self.next_writable, i);
-                let dst = self.buf.get_unchecked_mut(dst_index);
-                let src = input.get_unchecked(i).clone();
-                ptr::write(dst, src);
-            }
-        }
-        self.next_writable = self.wrap_add(self.next_writable, additional);
     }
 
-    // `O(1)`
+    // `O(1)` for types that don't need drop.
+    // `O(count)` otherwise.
     fn drop_many_front(&mut self, count: usize) -> usize {
-        // TODO improve name of real_count
-        let real_count = std::cmp::min(self.len(), count);
-        self.first_readable = self.wrap_add(
-            self.first_readable, real_count);
-        real_count
+        self.drop_front(count)
     }
 
     // `O(min(self.len(), output.len()))`
     fn read_many_front(&self, output: &mut [T]) -> usize {
-        let real_count = std::cmp::min(self.len(), output.len());
-        for i in 0..real_count {
-            // Unsafe code so this can be optimised to a memcpy (or something
-            // similarly fast) when T is Copy. LLVM is easily confused, so any
-            // extra operations during the loop can prevent this optimisation.
-            unsafe {
-                let dst = output.get_unchecked_mut(i);
-                let src_index = self.wrap_add(self.first_readable, i);
-                let src = self.buf.get_unchecked(src_index).clone();
-                ptr::write(dst, src);
-            }
+        let real_count = cmp::min(self.len(), output.len());
+        unsafe {
+            read_wrapping(self.ptr(), self.cap(), self.first_readable,
+                          &mut output[..real_count]);
         }
         real_count
     }
 }
 
+impl<T: Clone> SliceRingExt<T> for SliceRingImpl<T> {
+    // `O(input.len())`
+    fn try_push_many_back(&mut self, input: &[T]) -> Result<(), TryReserveError> {
+        // make enough space
+        SliceRingImpl::try_reserve(self, input.len())?;
+        self.write_back(input);
+        Ok(())
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        SliceRingImpl::try_reserve(self, additional)
+    }
+
+    // `O(1)`
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        SliceRingImpl::read_slices_front(self, count)
+    }
+
+    // `O(input.len())` plus the growth
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T)
+    {
+        let end = offset.checked_add(input.len()).expect("capacity overflow");
+        let len = self.len();
+        if len < end {
+            self.push_default_back(end - len);
+        }
+        let cap = self.cap();
+        let start = self.wrap_add(self.first_readable, offset);
+        let buf = self.ptr_mut();
+        unsafe {
+            combine_wrapping(buf, cap, start, input, f);
+        }
+    }
+
+    // `O(input.len())`
+    fn push_many_front(&mut self, input: &[T]) {
+        if let Err(err) = SliceRingImpl::try_reserve(self, input.len()) {
+            panic!("{}", err);
+        }
+        self.write_front(input);
+    }
+
+    // `O(1)` for types that don't need drop.
+    // `O(count)` otherwise.
+    fn drop_many_back(&mut self, count: usize) -> usize {
+        self.drop_back(count)
+    }
+}
+
 /// macro containing a test run that is used to test and benchmark
 /// different implementations of the `SliceRing` trait
 #[macro_export]
@@ -0,0 +1,297 @@
+//! ring whose memory is mapped twice back-to-back
+
+use std::cmp;
+use std::io;
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use libc;
+
+use super::{SliceRing, SliceRingExt, TryReserveError};
+
+/// ring whose underlying memory is mapped twice back-to-back
+/// into virtual memory.
+/// writing past the end of the first mapping writes to the start
+/// of the buffer.
+/// any range of elements is therefore one contiguous slice
+/// without copying or rotating: windows never straddle a wrap point.
+///
+/// the capacity is rounded up to a multiple of the page size.
+/// uses `memfd_create` and `mmap` and is only available on linux
+/// with the `mirrored` feature.
+///
+/// ```
+/// use strider::{MirroredSliceRing, SliceRing};
+///
+/// let mut ring = MirroredSliceRing::<f32>::with_capacity(1024).unwrap();
+/// let capacity = ring.capacity();
+/// ring.push_many_back(&vec![0.0; capacity - 2]);
+/// ring.drop_many_front(capacity - 4);
+/// ring.push_many_back(&[1.0, 2.0, 3.0, 4.0]);
+/// // the window wraps around the end of the buffer but is still one slice
+/// assert_eq!(ring.read_front(6), &[0.0, 0.0, 1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub struct MirroredSliceRing<T: Copy> {
+    /// start of the first of the two mappings.
+    /// the second mapping starts `cap` elements later.
+    ptr: *mut T,
+    /// number of elements in one mapping
+    cap: usize,
+    /// index of the first element in the first mapping
+    first_readable: usize,
+    len: usize,
+}
+
+unsafe impl<T: Copy + Send> Send for MirroredSliceRing<T> {}
+unsafe impl<T: Copy + Sync> Sync for MirroredSliceRing<T> {}
+
+fn page_size() -> usize {
+    unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// returns the number of bytes of one mapping that holds at least
+/// `required` elements of `size` bytes.
+/// a multiple of the page size and of `size`
+/// so elements line up in both mappings.
+fn bytes_for(required: usize, size: usize) -> Option<usize> {
+    let page_size = page_size();
+    let unit = (page_size / gcd(page_size, size)).checked_mul(size)?;
+    let units = cmp::max(required.checked_mul(size)?, 1).checked_add(unit - 1)? / unit;
+    let bytes = units.checked_mul(unit)?;
+    // both mappings together must fit into `isize::MAX` bytes
+    if bytes > isize::MAX as usize / 2 {
+        return None;
+    }
+    Some(bytes)
+}
+
+/// maps `bytes` bytes of memory twice back-to-back.
+/// `bytes` must be a multiple of the page size.
+unsafe fn map_mirrored(bytes: usize) -> io::Result<*mut u8> {
+    let fd = libc::memfd_create(b"strider\0".as_ptr() as *const libc::c_char,
+                                libc::MFD_CLOEXEC);
+    if fd < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let result = map_mirrored_fd(fd, bytes);
+    // the mappings keep the memory alive
+    libc::close(fd);
+    result
+}
+
+unsafe fn map_mirrored_fd(fd: libc::c_int, bytes: usize) -> io::Result<*mut u8> {
+    if libc::ftruncate(fd, bytes as libc::off_t) != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // reserve address space for both mappings
+    let base = libc::mmap(ptr::null_mut(), 2 * bytes, libc::PROT_NONE,
+                          libc::MAP_PRIVATE | libc::MAP_ANONYMOUS, -1, 0);
+    if base == libc::MAP_FAILED {
+        return Err(io::Error::last_os_error());
+    }
+    for half in 0..2 {
+        let addr = (base as *mut u8).add(half * bytes) as *mut libc::c_void;
+        let mapped = libc::mmap(addr, bytes, libc::PROT_READ | libc::PROT_WRITE,
+                                libc::MAP_SHARED | libc::MAP_FIXED, fd, 0);
+        if mapped == libc::MAP_FAILED {
+            let err = io::Error::last_os_error();
+            libc::munmap(base, 2 * bytes);
+            return Err(err);
+        }
+    }
+    Ok(base as *mut u8)
+}
+
+impl<T: Copy> MirroredSliceRing<T> {
+    /// creates an empty `MirroredSliceRing` with a capacity of one page
+    pub fn new() -> io::Result<MirroredSliceRing<T>> {
+        MirroredSliceRing::with_capacity(1)
+    }
+
+    /// creates an empty `MirroredSliceRing` with space for at least `n` elements.
+    /// panics for zero sized types.
+    pub fn with_capacity(n: usize) -> io::Result<MirroredSliceRing<T>> {
+        let size = mem::size_of::<T>();
+        assert!(size != 0, "MirroredSliceRing doesn't support zero sized types");
+        let bytes = bytes_for(n, size)
+            .ok_or_else(|| io::Error::other("capacity overflow"))?;
+        let ptr = unsafe { map_mirrored(bytes)? };
+        Ok(MirroredSliceRing {
+            ptr: ptr as *mut T,
+            cap: bytes / size,
+            first_readable: 0,
+            len: 0,
+        })
+    }
+
+    /// the number of elements the ring can hold without allocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// returns the number of elements in the ring
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// returns `true` if the ring contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// returns all elements as one slice
+    // `O(1)`
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.add(self.first_readable), self.len) }
+    }
+
+    /// returns all elements as one mutable slice
+    // `O(1)`
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.add(self.first_readable), self.len) }
+    }
+
+    /// returns the first `count` elements as one slice.
+    /// the slice is shorter than `count` if there are less elements present.
+    // `O(1)`
+    #[inline]
+    pub fn read_front(&self, count: usize) -> &[T] {
+        &self.as_slice()[..cmp::min(self.len, count)]
+    }
+
+    /// removes all elements
+    pub fn clear(&mut self) {
+        self.first_readable = 0;
+        self.len = 0;
+    }
+
+    fn bytes(&self) -> usize {
+        self.cap * mem::size_of::<T>()
+    }
+}
+
+impl<T: Copy> Drop for MirroredSliceRing<T> {
+    fn drop(&mut self) {
+        unsafe {
+            libc::munmap(self.ptr as *mut libc::c_void, 2 * self.bytes());
+        }
+    }
+}
+
+impl<T: Copy> SliceRing<T> for MirroredSliceRing<T> {
+    // `O(input.len())`
+    fn push_many_back(&mut self, input: &[T]) {
+        if let Err(err) = self.try_push_many_back(input) {
+            panic!("{}", err);
+        }
+    }
+
+    // `O(1)`
+    fn drop_many_front(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len, count);
+        self.first_readable = (self.first_readable + count) % self.cap;
+        self.len -= count;
+        count
+    }
+
+    // `O(min(self.len(), output.len()))`
+    fn read_many_front(&self, output: &mut [T]) -> usize {
+        let window = self.read_front(output.len());
+        output[..window.len()].copy_from_slice(window);
+        window.len()
+    }
+}
+
+impl<T: Copy> SliceRingExt<T> for MirroredSliceRing<T> {
+    // `O(input.len())`
+    fn try_push_many_back(&mut self, input: &[T]) -> Result<(), TryReserveError> {
+        self.try_reserve(input.len())?;
+        let next_writable = self.first_readable + self.len;
+        unsafe {
+            ptr::copy_nonoverlapping(input.as_ptr(), self.ptr.add(next_writable), input.len());
+        }
+        self.len += input.len();
+        Ok(())
+    }
+
+    // maps a new larger buffer and copies the elements over
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self.len.checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if required <= self.cap {
+            return Ok(());
+        }
+        let size = mem::size_of::<T>();
+        let bytes = bytes_for(cmp::max(required, self.cap.saturating_mul(2)), size)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        let ptr = unsafe {
+            map_mirrored(bytes).map_err(|_| TryReserveError::AllocError)?
+        } as *mut T;
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.add(self.first_readable), ptr, self.len);
+            libc::munmap(self.ptr as *mut libc::c_void, 2 * self.bytes());
+        }
+        self.ptr = ptr;
+        self.cap = bytes / size;
+        self.first_readable = 0;
+        Ok(())
+    }
+
+    // `O(1)`
+    fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        (self.read_front(count), &[])
+    }
+
+    // `O(input.len())` plus the growth
+    fn combine_many_at<F>(&mut self, offset: usize, input: &[T], mut f: F)
+        where T: Clone + Default, F: FnMut(&mut T, &T)
+    {
+        let end = offset.checked_add(input.len()).expect("capacity overflow");
+        if self.len < end {
+            if let Err(err) = self.try_reserve(end - self.len) {
+                panic!("{}", err);
+            }
+            let next_writable = self.first_readable + self.len;
+            for i in 0..end - self.len {
+                unsafe {
+                    ptr::write(self.ptr.add(next_writable + i), T::default());
+                }
+            }
+            self.len = end;
+        }
+        for (element, value) in self.as_mut_slice()[offset..end].iter_mut().zip(input) {
+            f(element, value);
+        }
+    }
+
+    // `O(input.len())`
+    fn push_many_front(&mut self, input: &[T]) {
+        if let Err(err) = self.try_reserve(input.len()) {
+            panic!("{}", err);
+        }
+        // the slots before the front are contiguous in the second mapping
+        let first_readable = self.first_readable + self.cap - input.len();
+        unsafe {
+            ptr::copy_nonoverlapping(input.as_ptr(), self.ptr.add(first_readable), input.len());
+        }
+        self.first_readable = first_readable % self.cap;
+        self.len += input.len();
+    }
+
+    // `O(1)`
+    fn drop_many_back(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len, count);
+        self.len -= count;
+        count
+    }
+}
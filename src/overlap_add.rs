@@ -0,0 +1,130 @@
+//! ring that sums overlapping frames for resynthesis
+
+use core::cmp;
+use core::ops::AddAssign;
+
+use super::{SliceRingExt, SliceRingImpl};
+
+/// the dual of reading windows from a [SliceRingImpl](struct.SliceRingImpl.html):
+/// sums frames that overlap into a stream of values.
+/// used to resynthesize a signal from processed frames
+/// of a short-time fourier transform.
+///
+/// each frame is added to the values at the current frame position
+/// which then moves on by `step_size`.
+/// values before the current frame position are finished:
+/// no future frame can overlap them.
+/// only finished values can be popped from the front.
+///
+/// ```
+/// use strider::OverlapAddRing;
+///
+/// let mut ring = OverlapAddRing::new(2);
+/// ring.add_frame(&[1, 1, 1, 1]);
+/// ring.add_frame(&[1, 1, 1, 1]);
+/// assert_eq!(ring.ready(), 4);
+///
+/// let mut output = [0; 6];
+/// assert_eq!(ring.pop_many_front(&mut output), 4);
+/// assert_eq!(output[..4], [1, 1, 2, 2]);
+///
+/// // the stream ended. no more frames overlap the rest
+/// ring.finish();
+/// assert_eq!(ring.pop_many_front(&mut output), 2);
+/// assert_eq!(output[..2], [1, 1]);
+/// ```
+pub struct OverlapAddRing<T> {
+    ring: SliceRingImpl<T>,
+    step_size: usize,
+    /// where the next frame starts relative to the front.
+    /// the ring always holds at least this many values.
+    frame_offset: usize,
+}
+
+impl<T> OverlapAddRing<T> {
+    /// creates an empty `OverlapAddRing` whose frames start
+    /// `step_size` values apart.
+    /// panics if `step_size` is zero.
+    pub fn new(step_size: usize) -> OverlapAddRing<T> {
+        assert!(step_size != 0, "step size must not be zero");
+        OverlapAddRing {
+            ring: SliceRingImpl::new(),
+            step_size,
+            frame_offset: 0,
+        }
+    }
+
+    /// the number of values between the starts of two frames
+    #[inline]
+    pub fn step_size(&self) -> usize {
+        self.step_size
+    }
+
+    /// returns the number of values in the ring,
+    /// finished or not
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// returns `true` if the ring contains no values
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    /// returns how many values are finished and can be popped
+    #[inline]
+    pub fn ready(&self) -> usize {
+        self.frame_offset
+    }
+
+    /// marks all values as finished.
+    /// call it after the last frame was added.
+    /// frames added afterwards start after the current back.
+    pub fn finish(&mut self) {
+        self.frame_offset = self.ring.len();
+    }
+
+    /// returns the finished values as two slices without copying them
+    // `O(1)`
+    #[inline]
+    pub fn read_slices_ready(&self) -> (&[T], &[T]) {
+        self.ring.read_slices_front(self.frame_offset)
+    }
+
+    /// removes and drops up to `count` finished values from the front.
+    /// returns how many values were dropped.
+    pub fn drop_many_front(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.frame_offset, count);
+        self.frame_offset -= count;
+        self.ring.drop_front(count)
+    }
+
+    /// moves up to `output.len()` finished values from the front
+    /// into `output`.
+    /// returns how many values were moved.
+    // `O(min(self.ready(), output.len()))`
+    pub fn pop_many_front(&mut self, output: &mut [T]) -> usize {
+        let count = cmp::min(self.frame_offset, output.len());
+        self.frame_offset -= count;
+        self.ring.pop_many_front(&mut output[..count])
+    }
+}
+
+impl<T: Clone + Default + AddAssign> OverlapAddRing<T> {
+    /// adds `frame` to the values at the current frame position
+    /// and moves the frame position on by the step size.
+    /// values the ring doesn't hold yet start at `T::default()`.
+    // `O(frame.len() + self.step_size())`
+    pub fn add_frame(&mut self, frame: &[T]) {
+        let offset = self.frame_offset;
+        self.ring.combine_many_at(offset, frame, |sum, value| *sum += value.clone());
+        // the ring holds at least the values up to the next frame
+        let len = self.ring.len();
+        if len < offset + self.step_size {
+            self.ring.push_default_back(offset + self.step_size - len);
+        }
+        self.frame_offset += self.step_size;
+    }
+}
@@ -0,0 +1,357 @@
+//! ring shared between threads that lets consumers and producers wait
+
+use std::sync::{Condvar, Mutex, MutexGuard};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::mem;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+
+use super::{BoundedSliceRing, Overflow, SliceRing};
+
+/// ring with a fixed capacity that can be shared between threads.
+/// usually behind an `Arc`.
+///
+/// a consumer can block until enough elements for a window are there
+/// and a producer can block until enough space is free.
+/// with the `async` feature both can also be awaited
+/// so a task of any executor can consume windows
+/// that a thread is filling.
+///
+/// a producer that is done calls [close](#method.close)
+/// to release consumers that are still waiting.
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+/// use strider::SharedSliceRing;
+///
+/// let ring = Arc::new(SharedSliceRing::<i32>::new(16));
+/// let producer = ring.clone();
+/// let audio = thread::spawn(move || {
+///     for chunk in (0..100).collect::<Vec<i32>>().chunks(10) {
+///         producer.push_many_back_blocking(chunk);
+///     }
+///     producer.close();
+/// });
+///
+/// let mut window = [0; 8];
+/// let mut windows = 0;
+/// while ring.wait_for_len(window.len()) >= window.len() {
+///     ring.read_many_front(&mut window);
+///     ring.drop_many_front(4);
+///     windows += 1;
+/// }
+/// audio.join().unwrap();
+/// assert_eq!(windows, 24);
+/// ```
+pub struct SharedSliceRing<T> {
+    inner: Mutex<Inner<T>>,
+    /// notified when elements are pushed
+    readable: Condvar,
+    /// notified when elements are dropped
+    writable: Condvar,
+}
+
+struct Inner<T> {
+    ring: BoundedSliceRing<T>,
+    closed: bool,
+    /// tasks waiting for elements
+    #[cfg(feature = "async")]
+    readers: Vec<Waker>,
+    /// tasks waiting for free space
+    #[cfg(feature = "async")]
+    writers: Vec<Waker>,
+}
+
+impl<T> SharedSliceRing<T> {
+    /// creates an empty `SharedSliceRing` that holds at most
+    /// `capacity` elements
+    pub fn new(capacity: usize) -> SharedSliceRing<T> {
+        SharedSliceRing {
+            inner: Mutex::new(Inner {
+                ring: BoundedSliceRing::new(capacity, Overflow::Reject),
+                closed: false,
+                #[cfg(feature = "async")]
+                readers: Vec::new(),
+                #[cfg(feature = "async")]
+                writers: Vec::new(),
+            }),
+            readable: Condvar::new(),
+            writable: Condvar::new(),
+        }
+    }
+
+    /// the ring stays consistent even if a `clone` panicked
+    /// while the lock was held so poisoning is ignored
+    fn lock(&self) -> MutexGuard<'_, Inner<T>> {
+        self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// wakes the threads waiting for elements.
+    /// returns the tasks waiting for elements
+    /// which must be woken after the lock is released.
+    fn notify_readers(&self, _inner: &mut Inner<T>) -> Wakers {
+        self.readable.notify_all();
+        Wakers {
+            #[cfg(feature = "async")]
+            wakers: mem::take(&mut _inner.readers),
+        }
+    }
+
+    /// wakes the threads waiting for free space.
+    /// returns the tasks waiting for free space
+    /// which must be woken after the lock is released.
+    fn notify_writers(&self, _inner: &mut Inner<T>) -> Wakers {
+        self.writable.notify_all();
+        Wakers {
+            #[cfg(feature = "async")]
+            wakers: mem::take(&mut _inner.writers),
+        }
+    }
+
+    /// the maximum number of elements this ring holds
+    pub fn capacity(&self) -> usize {
+        self.lock().ring.capacity()
+    }
+
+    /// returns the number of elements in the ring
+    pub fn len(&self) -> usize {
+        self.lock().ring.len()
+    }
+
+    /// returns `true` if the ring contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.lock().ring.is_empty()
+    }
+
+    /// returns how many more elements fit into the ring
+    pub fn free(&self) -> usize {
+        self.lock().ring.free()
+    }
+
+    /// removes and drops up to `count` elements from the front.
+    /// returns how many elements were dropped.
+    /// wakes producers waiting for free space.
+    pub fn drop_many_front(&self, count: usize) -> usize {
+        let mut inner = self.lock();
+        let dropped = inner.ring.drop_front(count);
+        if dropped != 0 {
+            let writers = self.notify_writers(&mut inner);
+            drop(inner);
+            writers.wake();
+        }
+        dropped
+    }
+
+    /// removes and drops all elements.
+    /// wakes producers waiting for free space.
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.ring.clear();
+        let writers = self.notify_writers(&mut inner);
+        drop(inner);
+        writers.wake();
+    }
+
+    /// marks the ring as closed and wakes everyone who is waiting.
+    /// waiting returns early from now on.
+    /// the elements still in the ring can be read as usual.
+    pub fn close(&self) {
+        let mut inner = self.lock();
+        inner.closed = true;
+        let readers = self.notify_readers(&mut inner);
+        let writers = self.notify_writers(&mut inner);
+        drop(inner);
+        readers.wake();
+        writers.wake();
+    }
+
+    /// returns `true` if [close](#method.close) was called
+    pub fn is_closed(&self) -> bool {
+        self.lock().closed
+    }
+
+    /// blocks until at least `count` elements are in the ring
+    /// or the ring is closed.
+    /// returns the number of elements in the ring.
+    /// that is less than `count` only if the ring is closed.
+    /// panics if `count` exceeds the capacity.
+    pub fn wait_for_len(&self, count: usize) -> usize {
+        let mut inner = self.lock();
+        assert!(count <= inner.ring.capacity(),
+                "waiting for {} elements in a ring of capacity {}", count, inner.ring.capacity());
+        while inner.ring.len() < count && !inner.closed {
+            inner = self.readable.wait(inner).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        inner.ring.len()
+    }
+
+    /// blocks until at least `count` more elements fit into the ring
+    /// or the ring is closed.
+    /// returns how many more elements fit.
+    /// that is less than `count` only if the ring is closed.
+    /// panics if `count` exceeds the capacity.
+    pub fn wait_for_free(&self, count: usize) -> usize {
+        let mut inner = self.lock();
+        assert!(count <= inner.ring.capacity(),
+                "waiting for {} free slots in a ring of capacity {}", count, inner.ring.capacity());
+        while inner.ring.free() < count && !inner.closed {
+            inner = self.writable.wait(inner).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        inner.ring.free()
+    }
+
+    /// returns a future that resolves once at least `count` elements
+    /// are in the ring or the ring is closed.
+    /// resolves to the number of elements in the ring
+    /// like [wait_for_len](#method.wait_for_len).
+    /// panics if `count` exceeds the capacity.
+    #[cfg(feature = "async")]
+    pub fn wait_for_len_async(&self, count: usize) -> WaitForLen<'_, T> {
+        let capacity = self.capacity();
+        assert!(count <= capacity,
+                "waiting for {} elements in a ring of capacity {}", count, capacity);
+        WaitForLen { ring: self, count }
+    }
+
+    /// returns a future that resolves once at least `count` more elements
+    /// fit into the ring or the ring is closed.
+    /// resolves to how many more elements fit
+    /// like [wait_for_free](#method.wait_for_free).
+    /// panics if `count` exceeds the capacity.
+    #[cfg(feature = "async")]
+    pub fn wait_for_free_async(&self, count: usize) -> WaitForFree<'_, T> {
+        let capacity = self.capacity();
+        assert!(count <= capacity,
+                "waiting for {} free slots in a ring of capacity {}", count, capacity);
+        WaitForFree { ring: self, count }
+    }
+}
+
+impl<T: Clone> SharedSliceRing<T> {
+    /// appends as many of `values` to the back as fit right now.
+    /// returns how many values were appended.
+    /// wakes consumers waiting for elements.
+    pub fn push_many_back(&self, values: &[T]) -> usize {
+        let mut inner = self.lock();
+        let pushed = inner.ring.push_many_back_with(values, Overflow::Reject);
+        if pushed != 0 {
+            let readers = self.notify_readers(&mut inner);
+            drop(inner);
+            readers.wake();
+        }
+        pushed
+    }
+
+    /// appends all of `values` to the back.
+    /// blocks while the ring is full.
+    /// returns how many values were appended.
+    /// that is less than `values.len()` only if the ring was closed.
+    pub fn push_many_back_blocking(&self, values: &[T]) -> usize {
+        let mut inner = self.lock();
+        let mut pushed = 0;
+        loop {
+            let count = inner.ring.push_many_back_with(&values[pushed..], Overflow::Reject);
+            if count != 0 {
+                pushed += count;
+                let readers = self.notify_readers(&mut inner);
+                drop(inner);
+                readers.wake();
+                if pushed == values.len() {
+                    return pushed;
+                }
+                // space may have been freed while the lock was released
+                // so try again before waiting
+                inner = self.lock();
+                continue;
+            }
+            if pushed == values.len() || inner.closed {
+                return pushed;
+            }
+            inner = self.writable.wait(inner).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    /// clones up to `output.len()` elements from the front into `output`.
+    /// returns how many elements were read.
+    pub fn read_many_front(&self, output: &mut [T]) -> usize {
+        self.lock().ring.read_many_front(output)
+    }
+}
+
+/// tasks taken out of the ring while the lock was held.
+/// a woken task may be polled right away on the same thread
+/// and lock the ring again so they are woken after the lock is released.
+#[must_use]
+struct Wakers {
+    #[cfg(feature = "async")]
+    wakers: Vec<Waker>,
+}
+
+impl Wakers {
+    fn wake(self) {
+        #[cfg(feature = "async")]
+        for waker in self.wakers {
+            waker.wake();
+        }
+    }
+}
+
+/// future returned by
+/// [SharedSliceRing::wait_for_len_async](struct.SharedSliceRing.html#method.wait_for_len_async)
+#[cfg(feature = "async")]
+pub struct WaitForLen<'a, T: 'a> {
+    ring: &'a SharedSliceRing<T>,
+    count: usize,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> Future for WaitForLen<'a, T> {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let mut inner = self.ring.lock();
+        let len = inner.ring.len();
+        if len >= self.count || inner.closed {
+            return Poll::Ready(len);
+        }
+        register(&mut inner.readers, cx.waker());
+        Poll::Pending
+    }
+}
+
+/// future returned by
+/// [SharedSliceRing::wait_for_free_async](struct.SharedSliceRing.html#method.wait_for_free_async)
+#[cfg(feature = "async")]
+pub struct WaitForFree<'a, T: 'a> {
+    ring: &'a SharedSliceRing<T>,
+    count: usize,
+}
+
+#[cfg(feature = "async")]
+impl<'a, T> Future for WaitForFree<'a, T> {
+    type Output = usize;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<usize> {
+        let mut inner = self.ring.lock();
+        let free = inner.ring.free();
+        if free >= self.count || inner.closed {
+            return Poll::Ready(free);
+        }
+        register(&mut inner.writers, cx.waker());
+        Poll::Pending
+    }
+}
+
+/// adds `waker` to `wakers` unless it would wake the same task
+/// as one that is already there
+#[cfg(feature = "async")]
+fn register(wakers: &mut Vec<Waker>, waker: &Waker) {
+    if !wakers.iter().any(|registered| registered.will_wake(waker)) {
+        wakers.push(waker.clone());
+    }
+}
@@ -0,0 +1,310 @@
+//! lock-free ring split into a producer and a consumer half
+
+use alloc::vec::Vec;
+use core::cmp;
+use core::marker::PhantomData;
+use core::mem;
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+use super::{count, drop_wrapping, read_wrapping, wrap_index, wrapped_slices, write_wrapping};
+#[cfg(loom)]
+use super::wrapped_ranges;
+use super::SliceRingImpl;
+
+/// under `--cfg loom` the atomics are replaced by loom's
+/// so model tests can explore all interleavings
+#[cfg(loom)]
+mod sync {
+    pub use loom::sync::Arc;
+    pub use loom::sync::atomic::{AtomicUsize, Ordering};
+}
+#[cfg(not(loom))]
+mod sync {
+    pub use alloc::sync::Arc;
+    pub use core::sync::atomic::{AtomicUsize, Ordering};
+}
+
+use self::sync::{Arc, AtomicUsize, Ordering};
+
+/// the state shared by a [Producer](struct.Producer.html)
+/// and a [Consumer](struct.Consumer.html).
+///
+/// only the producer writes `next_writable`
+/// and only the consumer writes `first_readable`.
+/// the slots between them belong to the consumer,
+/// all other slots belong to the producer.
+/// each side publishes with a release store after it is done
+/// with the slots it hands over and reads the other index
+/// with an acquire load.
+struct Shared<T> {
+    /// points into `buf`. taken once so no references
+    /// to `buf` are created while both halves access the slots.
+    ptr: *mut T,
+    /// number of slots. always a power of two.
+    cap: usize,
+    first_readable: AtomicUsize,
+    next_writable: AtomicUsize,
+    /// only kept to free the memory on drop
+    _buf: Vec<MaybeUninit<T>>,
+    /// lets loom detect unsynchronized accesses to the slots
+    #[cfg(loom)]
+    slots: Vec<loom::cell::UnsafeCell<()>>,
+}
+
+// the slots are only ever accessed by the half that owns them
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+impl<T> Shared<T> {
+    /// tells loom that the `count` slots starting at `start` are written
+    #[inline]
+    fn track_mut(&self, start: usize, count: usize) {
+        #[cfg(loom)]
+        {
+            if !self.slots.is_empty() {
+                let (front, back) = wrapped_ranges(start, count, self.cap);
+                for index in front.chain(back) {
+                    self.slots[index].with_mut(|_| ());
+                }
+            }
+        }
+        #[cfg(not(loom))]
+        let _ = (start, count);
+    }
+
+    /// tells loom that the `count` slots starting at `start` are read
+    #[inline]
+    fn track(&self, start: usize, count: usize) {
+        #[cfg(loom)]
+        {
+            if !self.slots.is_empty() {
+                let (front, back) = wrapped_ranges(start, count, self.cap);
+                for index in front.chain(back) {
+                    self.slots[index].with(|_| ());
+                }
+            }
+        }
+        #[cfg(not(loom))]
+        let _ = (start, count);
+    }
+}
+
+impl<T> Drop for Shared<T> {
+    fn drop(&mut self) {
+        // both halves are gone. nothing else accesses the slots
+        let first_readable = self.first_readable.load(Ordering::Acquire);
+        let next_writable = self.next_writable.load(Ordering::Acquire);
+        unsafe {
+            drop_wrapping(self.ptr, self.cap,
+                          first_readable, count(first_readable, next_writable, self.cap));
+        }
+    }
+}
+
+/// publishes the local copy of an index when it goes out of scope.
+/// this way slots handed over before a panicking `clone` or destructor
+/// are still handed over.
+struct Publish<'a> {
+    index: &'a AtomicUsize,
+    local: &'a mut usize,
+}
+
+impl<'a> Drop for Publish<'a> {
+    fn drop(&mut self) {
+        self.index.store(*self.local, Ordering::Release);
+    }
+}
+
+/// appending half of a ring that was [split](struct.SliceRingImpl.html#method.split).
+/// can be moved to another thread than its [Consumer](struct.Consumer.html).
+/// never blocks and never allocates.
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+    /// the producer's own copy of `shared.next_writable`
+    next_writable: usize,
+}
+
+/// reading half of a ring that was [split](struct.SliceRingImpl.html#method.split).
+/// can be moved to another thread than its [Producer](struct.Producer.html).
+/// never blocks and never allocates.
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+    /// the consumer's own copy of `shared.first_readable`
+    first_readable: usize,
+    /// hands out references to elements.
+    /// so it's only `Sync` if `T` is
+    marker: PhantomData<T>,
+}
+
+impl<T> SliceRingImpl<T> {
+    /// splits this ring into a [Producer](struct.Producer.html)
+    /// that appends to the back
+    /// and a [Consumer](struct.Consumer.html) that reads and drops
+    /// from the front.
+    /// both halves can be used from different threads at the same time
+    /// without locking.
+    /// wait-free on both sides.
+    ///
+    /// the elements already in this ring are kept.
+    /// the capacity is fixed from now on:
+    /// reserve enough before splitting.
+    ///
+    /// ```
+    /// use std::thread;
+    /// use strider::SliceRingImpl;
+    ///
+    /// let (mut producer, mut consumer) = SliceRingImpl::<i32>::with_capacity(1024).split();
+    /// let audio = thread::spawn(move || {
+    ///     let mut pushed = 0;
+    ///     while pushed < 100 {
+    ///         pushed += producer.push_many_back(&[1, 2, 3, 4][..(100 - pushed).min(4)]);
+    ///     }
+    /// });
+    /// let mut window = [0; 4];
+    /// let mut sum = 0;
+    /// let mut read = 0;
+    /// while read < 100 {
+    ///     let count = consumer.read_many_front(&mut window);
+    ///     sum += window[..count].iter().sum::<i32>();
+    ///     read += consumer.drop_many_front(count);
+    /// }
+    /// audio.join().unwrap();
+    /// assert_eq!(sum, 250);
+    /// ```
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        // the elements move to `Shared` without being dropped
+        let mut ring = ManuallyDrop::new(self);
+        let cap = ring.cap();
+        let first_readable = ring.first_readable;
+        let next_writable = ring.next_writable;
+        let mut buf = mem::take(&mut ring.buf);
+        let shared = Arc::new(Shared {
+            ptr: buf.as_mut_ptr() as *mut T,
+            cap,
+            first_readable: AtomicUsize::new(first_readable),
+            next_writable: AtomicUsize::new(next_writable),
+            #[cfg(loom)]
+            slots: (0..buf.len()).map(|_| loom::cell::UnsafeCell::new(())).collect(),
+            _buf: buf,
+        });
+        let producer = Producer {
+            shared: shared.clone(),
+            next_writable,
+        };
+        let consumer = Consumer {
+            shared,
+            first_readable,
+            marker: PhantomData,
+        };
+        (producer, consumer)
+    }
+}
+
+impl<T> Producer<T> {
+    /// the maximum number of elements the ring holds
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.cap - 1
+    }
+
+    /// returns how many more elements can be pushed right now.
+    /// more might fit by the time they are pushed
+    /// since the consumer can drop elements concurrently.
+    #[inline]
+    pub fn free(&self) -> usize {
+        let first_readable = self.shared.first_readable.load(Ordering::Acquire);
+        self.capacity() - count(first_readable, self.next_writable, self.shared.cap)
+    }
+}
+
+impl<T: Clone> Producer<T> {
+    /// appends as many of `values` to the back as fit right now.
+    /// returns how many values were appended.
+    /// the rest is left for a later call.
+    // `O(values.len())`
+    pub fn push_many_back(&mut self, values: &[T]) -> usize {
+        let count = cmp::min(self.free(), values.len());
+        let shared = &*self.shared;
+        shared.track_mut(self.next_writable, count);
+        let publish = Publish {
+            index: &shared.next_writable,
+            local: &mut self.next_writable,
+        };
+        unsafe {
+            write_wrapping(shared.ptr, shared.cap, publish.local, &values[..count]);
+        }
+        count
+    }
+}
+
+impl<T> Consumer<T> {
+    /// the maximum number of elements the ring holds
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.shared.cap - 1
+    }
+
+    /// returns the number of elements that can be read right now.
+    /// more might be there by the time they are read
+    /// since the producer can push elements concurrently.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let next_writable = self.shared.next_writable.load(Ordering::Acquire);
+        count(self.first_readable, next_writable, self.shared.cap)
+    }
+
+    /// returns `true` if there are no elements to read right now
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns the first `count` elements as two slices without copying them.
+    /// the second slice is empty unless the elements wrap around
+    /// the end of the underlying buffer.
+    // `O(1)`
+    pub fn read_slices_front(&self, count: usize) -> (&[T], &[T]) {
+        let count = cmp::min(self.len(), count);
+        let shared = &*self.shared;
+        shared.track(self.first_readable, count);
+        unsafe {
+            wrapped_slices(shared.ptr, shared.cap, self.first_readable, count)
+        }
+    }
+
+    /// removes and drops up to `count` elements from the front.
+    /// returns how many elements were dropped.
+    /// the producer can reuse their slots afterwards.
+    // `O(1)` for types that don't need drop.
+    // `O(count)` otherwise.
+    pub fn drop_many_front(&mut self, count: usize) -> usize {
+        let count = cmp::min(self.len(), count);
+        let shared = &*self.shared;
+        let start = self.first_readable;
+        shared.track_mut(start, count);
+        let publish = Publish {
+            index: &shared.first_readable,
+            local: &mut self.first_readable,
+        };
+        *publish.local = wrap_index(start.wrapping_add(count), shared.cap);
+        unsafe {
+            drop_wrapping(shared.ptr, shared.cap, start, count);
+        }
+        count
+    }
+}
+
+impl<T: Clone> Consumer<T> {
+    /// clones up to `output.len()` elements from the front into `output`.
+    /// returns how many elements were read.
+    // `O(min(self.len(), output.len()))`
+    pub fn read_many_front(&self, output: &mut [T]) -> usize {
+        let count = cmp::min(self.len(), output.len());
+        let shared = &*self.shared;
+        shared.track(self.first_readable, count);
+        unsafe {
+            read_wrapping(shared.ptr, shared.cap, self.first_readable, &mut output[..count]);
+        }
+        count
+    }
+}
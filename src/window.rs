@@ -0,0 +1,114 @@
+//! window functions to multiply windows with before a fourier transform.
+//!
+//! compute a table once with [Window::periodic](enum.Window.html#method.periodic)
+//! and apply it while reading with
+//! [SliceRingExt::read_many_front_windowed](../trait.SliceRingExt.html#method.read_many_front_windowed).
+//!
+//! ```
+//! use strider::{SliceRing, SliceRingExt, SliceRingImpl};
+//! use strider::window::Window;
+//!
+//! let table = Window::Hann.periodic::<f32>(4);
+//! assert_eq!(table, vec![0.0, 0.5, 1.0, 0.5]);
+//!
+//! let mut ring = SliceRingImpl::new();
+//! ring.push_many_back(&[2.0, 2.0, 2.0, 2.0, 2.0]);
+//! let mut output = [0.0; 4];
+//! ring.read_many_front_windowed(&mut output, &table);
+//! assert_eq!(output, [0.0, 1.0, 2.0, 1.0]);
+//! ```
+
+use std::f64::consts::PI;
+
+/// a window function
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Window {
+    /// all coefficients are `1`. the same as not applying a window
+    Rectangular,
+    /// raised cosine that reaches zero at both ends
+    Hann,
+    /// raised cosine that doesn't quite reach zero at the ends
+    Hamming,
+    /// 4-term blackman-harris with a very low sidelobe level
+    BlackmanHarris,
+    /// kaiser window with the given `beta`.
+    /// larger values trade a wider main lobe for lower sidelobes.
+    /// `0` is rectangular. about `8.6` is similar to blackman-harris.
+    Kaiser(f64),
+}
+
+/// types a window table can hold
+pub trait Coefficient: Copy {
+    /// converts a coefficient computed as `f64`
+    fn from_f64(value: f64) -> Self;
+}
+
+impl Coefficient for f32 {
+    #[inline]
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+}
+
+impl Coefficient for f64 {
+    #[inline]
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+}
+
+/// zeroth order modified bessel function of the first kind
+fn bessel_i0(x: f64) -> f64 {
+    // power series. converges quickly for the arguments kaiser windows use
+    let quarter_x_squared = x * x / 4.0;
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-16 {
+        term *= quarter_x_squared / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+impl Window {
+    /// returns the coefficient at `index` of a window
+    /// whose period is `period` values.
+    /// `index` runs from `0` to `period`. both ends are included.
+    pub fn coefficient(&self, index: usize, period: usize) -> f64 {
+        if period == 0 {
+            return 1.0;
+        }
+        let phase = 2.0 * PI * index as f64 / period as f64;
+        match *self {
+            Window::Rectangular => 1.0,
+            Window::Hann => 0.5 - 0.5 * phase.cos(),
+            Window::Hamming => 0.54 - 0.46 * phase.cos(),
+            Window::BlackmanHarris => {
+                0.35875 - 0.48829 * phase.cos() + 0.14128 * (2.0 * phase).cos()
+                    - 0.01168 * (3.0 * phase).cos()
+            }
+            Window::Kaiser(beta) => {
+                let x = 2.0 * index as f64 / period as f64 - 1.0;
+                bessel_i0(beta * (1.0 - x * x).max(0.0).sqrt()) / bessel_i0(beta)
+            }
+        }
+    }
+
+    /// returns the `size` coefficients of the periodic version of this window.
+    /// the table is one period of a periodic function
+    /// so the last coefficient is left out.
+    /// use this for spectral analysis.
+    pub fn periodic<T: Coefficient>(&self, size: usize) -> Vec<T> {
+        (0..size).map(|index| T::from_f64(self.coefficient(index, size))).collect()
+    }
+
+    /// returns the `size` coefficients of the symmetric version of this window.
+    /// the first and last coefficient are equal.
+    /// use this for filter design.
+    pub fn symmetric<T: Coefficient>(&self, size: usize) -> Vec<T> {
+        let period = size.saturating_sub(1);
+        (0..size).map(|index| T::from_f64(self.coefficient(index, period))).collect()
+    }
+}
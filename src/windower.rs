@@ -0,0 +1,300 @@
+//! moves a window through a stream of values pushed into a ring
+
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem;
+
+use super::SliceRingExt;
+
+/// what a [Windower](struct.Windower.html) does with the values
+/// at the end of a finished stream that don't fill a whole window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tail<T> {
+    /// never emit them
+    Drop,
+    /// emit one last window padded with `T::default()`
+    ZeroPad,
+    /// emit one last window padded with the given value
+    Pad(T),
+    /// emit one last window that is shorter than the window size
+    Short,
+}
+
+/// moves a window of `window_size` values in steps of `step_size`
+/// (also called hop size) through the values pushed into it.
+/// does the bookkeeping of the loop in the [crate docs](index.html#example).
+/// works with any [SliceRingExt](trait.SliceRingExt.html).
+///
+/// the step size may be larger than the window size.
+/// values between windows are then skipped,
+/// even those that are only pushed later.
+///
+/// by default the values at the end of a stream
+/// that don't fill a whole window are never emitted.
+/// call [set_tail](#method.set_tail) to pad them or emit a short window
+/// once the stream is [finished](#method.finish).
+///
+/// ```
+/// use strider::{SliceRingImpl, Tail, Windower};
+///
+/// let mut windower = Windower::new(SliceRingImpl::new(), 4, 2);
+/// windower.set_tail(Tail::Pad(b'_'));
+/// windower.push(b"ABCDEFG");
+/// assert_eq!(windower.ready(), 2);
+///
+/// // copy windows into your own buffer
+/// let mut window = [0; 4];
+/// assert_eq!(windower.next_window_into(&mut window), 4);
+/// assert_eq!(&window, b"ABCD");
+///
+/// // or borrow them from the windower
+/// assert_eq!(windower.next_window(), Some(&b"CDEF"[..]));
+/// assert_eq!(windower.next_window(), None);
+///
+/// windower.push(b"HIJK");
+/// assert_eq!(windower.next_window(), Some(&b"EFGH"[..]));
+/// assert_eq!(windower.next_window(), Some(&b"GHIJ"[..]));
+/// assert_eq!(windower.next_window(), None);
+///
+/// // the end of the stream
+/// windower.finish();
+/// assert_eq!(windower.next_window(), Some(&b"IJK_"[..]));
+/// assert_eq!(windower.next_window(), None);
+/// ```
+pub struct Windower<T, R> {
+    ring: R,
+    window_size: usize,
+    step_size: usize,
+    /// how many of the next pushed values to skip.
+    /// only non-zero if the step size is larger than the window size
+    /// and a step dropped every value in the ring.
+    skip: usize,
+    /// how many values at the back haven't been part of a window yet
+    unseen: usize,
+    /// whether `finish` was called
+    finished: bool,
+    /// whether to emit a last window for the values at the end
+    /// that don't fill a whole window.
+    /// padded with `padding` if that is present.
+    tail_window: bool,
+    padding: Option<T>,
+    /// the window handed out by `next_window`.
+    /// only allocated on first use.
+    window: Vec<T>,
+}
+
+/// returns the number of elements in `ring`
+#[inline]
+fn ring_len<T, R: SliceRingExt<T>>(ring: &R) -> usize {
+    let (front, back) = ring.read_slices_front(usize::MAX);
+    front.len() + back.len()
+}
+
+impl<T, R: SliceRingExt<T>> Windower<T, R> {
+    /// creates a `Windower` that moves a window of `window_size` values
+    /// through `ring` in steps of `step_size`.
+    /// values already in `ring` are part of the first window.
+    /// panics if `window_size` or `step_size` is zero.
+    pub fn new(ring: R, window_size: usize, step_size: usize) -> Windower<T, R> {
+        assert!(window_size != 0, "window size must not be zero");
+        assert!(step_size != 0, "step size must not be zero");
+        let unseen = ring_len(&ring);
+        Windower {
+            ring,
+            window_size,
+            step_size,
+            skip: 0,
+            unseen,
+            finished: false,
+            tail_window: false,
+            padding: None,
+            window: Vec::new(),
+        }
+    }
+
+    /// the number of values in each window
+    #[inline]
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// the number of values between the starts of two windows
+    #[inline]
+    pub fn step_size(&self) -> usize {
+        self.step_size
+    }
+
+    /// the underlying ring
+    #[inline]
+    pub fn ring(&self) -> &R {
+        &self.ring
+    }
+
+    /// returns the underlying ring.
+    /// values the windower would still skip are not skipped.
+    pub fn into_inner(self) -> R {
+        self.ring
+    }
+
+    /// returns the number of values pushed but not yet stepped over
+    #[inline]
+    pub fn len(&self) -> usize {
+        ring_len(&self.ring)
+    }
+
+    /// returns `true` if no values are waiting
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns how many windows can be read right now.
+    /// includes the last window at the end of a finished stream.
+    pub fn ready(&self) -> usize {
+        let len = self.len();
+        if len < self.window_size {
+            return self.has_tail_window(len, self.unseen) as usize;
+        }
+        let complete = (len - self.window_size) / self.step_size + 1;
+        // the state after reading all complete windows
+        let last_start = (complete - 1) * self.step_size;
+        let unseen = cmp::min(self.unseen, len - last_start - self.window_size);
+        let len = len.saturating_sub(last_start + self.step_size);
+        complete + self.has_tail_window(len, cmp::min(unseen, len)) as usize
+    }
+
+    /// returns how many more values must be pushed
+    /// until the next window is complete.
+    /// zero if a window can be read right now.
+    #[inline]
+    pub fn missing(&self) -> usize {
+        let len = self.len();
+        if self.has_tail_window(len, self.unseen) {
+            return 0;
+        }
+        (self.skip + self.window_size).saturating_sub(len)
+    }
+
+    /// marks the end of the stream.
+    /// the values that don't fill a whole window
+    /// are then emitted according to the [tail policy](#method.set_tail).
+    /// no values can be pushed afterwards.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// returns `true` if [finish](#method.finish) was called
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// whether there is a last window for `len` values
+    /// of which the last `unseen` weren't part of a window yet
+    #[inline]
+    fn has_tail_window(&self, len: usize, unseen: usize) -> bool {
+        self.finished && self.tail_window && len < self.window_size && unseen != 0
+    }
+
+    /// steps over the current window
+    fn step(&mut self) {
+        let len = self.len();
+        self.unseen = cmp::min(self.unseen, len - self.window_size);
+        let dropped = self.ring.drop_many_front(self.step_size);
+        self.skip = self.step_size - dropped;
+        self.unseen = cmp::min(self.unseen, len - dropped);
+    }
+}
+
+impl<T: Clone, R: SliceRingExt<T>> Windower<T, R> {
+    /// appends `values` to the back.
+    /// values that fall between windows are skipped right away.
+    /// panics if the stream is [finished](#method.finish).
+    // `O(values.len())`
+    pub fn push(&mut self, values: &[T]) {
+        assert!(!self.finished, "push after finish");
+        let skipped = cmp::min(self.skip, values.len());
+        self.skip -= skipped;
+        self.ring.push_many_back(&values[skipped..]);
+        self.unseen += values.len() - skipped;
+    }
+
+    /// pushes `window_size - step_size` copies of `value`
+    /// so the first values of the stream are in as many windows
+    /// as the values after them.
+    /// call it before pushing the stream.
+    pub fn pad_front(&mut self, value: T) {
+        let count = self.window_size.saturating_sub(self.step_size);
+        for _ in 0..count {
+            self.push(core::slice::from_ref(&value));
+        }
+    }
+
+    /// copies the next window into `output` and steps over it.
+    /// returns the length of the window.
+    /// that is less than the window size only for a
+    /// [short](enum.Tail.html#variant.Short) last window.
+    /// returns `0` and leaves `output` unchanged
+    /// if there are not enough values for a window yet.
+    /// panics if `output.len()` isn't the window size.
+    // `O(self.window_size())`
+    pub fn next_window_into(&mut self, output: &mut [T]) -> usize {
+        assert_eq!(output.len(), self.window_size,
+                   "output length must equal the window size");
+        let len = self.len();
+        if self.window_size <= len {
+            self.ring.read_many_front(output);
+            self.step();
+            return self.window_size;
+        }
+        if !self.has_tail_window(len, self.unseen) {
+            return 0;
+        }
+        self.ring.read_many_front(&mut output[..len]);
+        self.ring.drop_many_front(len);
+        self.unseen = 0;
+        match self.padding {
+            Some(ref padding) => {
+                for value in &mut output[len..] {
+                    *value = padding.clone();
+                }
+                self.window_size
+            }
+            None => len,
+        }
+    }
+}
+
+impl<T: Clone + Default, R: SliceRingExt<T>> Windower<T, R> {
+    /// sets what to do with the values at the end of a
+    /// [finished](#method.finish) stream that don't fill a whole window.
+    /// the default is [Tail::Drop](enum.Tail.html#variant.Drop).
+    pub fn set_tail(&mut self, tail: Tail<T>) {
+        let (tail_window, padding) = match tail {
+            Tail::Drop => (false, None),
+            Tail::ZeroPad => (true, Some(T::default())),
+            Tail::Pad(value) => (true, Some(value)),
+            Tail::Short => (true, None),
+        };
+        self.tail_window = tail_window;
+        self.padding = padding;
+    }
+
+    /// returns the next window and steps over it.
+    /// returns `None` if there are not enough values for a window yet.
+    /// the window is shorter than the window size only for a
+    /// [short](enum.Tail.html#variant.Short) last window.
+    /// the window is copied into a buffer owned by the windower
+    /// that is allocated on the first call.
+    // `O(self.window_size())`
+    pub fn next_window(&mut self) -> Option<&[T]> {
+        let mut window = mem::take(&mut self.window);
+        window.resize(self.window_size, T::default());
+        let len = self.next_window_into(&mut window);
+        self.window = window;
+        if len == 0 {
+            return None;
+        }
+        Some(&self.window[..len])
+    }
+}
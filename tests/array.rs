@@ -0,0 +1,107 @@
+#[macro_use]
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::{ArraySliceRing, Overflow};
+use strider::TryReserveError;
+
+mod common;
+use common::{Counters, DropCounter};
+
+fn contents<const N: usize>(ring: &ArraySliceRing<u32, N>) -> Vec<u32> {
+    let mut output = vec![0; ring.len()];
+    ring.read_many_front(&mut output[..]);
+    output
+}
+
+#[test]
+fn test_slice_ring_array() {
+    test_slice_ring!(ArraySliceRing::<i32, 8192>::new());
+}
+
+#[test]
+fn test_capacity() {
+    let ring = ArraySliceRing::<u32, 8>::new();
+    assert_eq!(ring.capacity(), 7);
+    assert_eq!(ring.free(), 7);
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn test_wrapping() {
+    let mut ring = ArraySliceRing::<u32, 8>::new();
+    for i in 0..100 {
+        ring.push_many_back(&[3 * i, 3 * i + 1, 3 * i + 2]);
+        assert_eq!(contents(&ring)[..3], [3 * i, 3 * i + 1, 3 * i + 2]);
+        assert_eq!(ring.drop_many_front(3), 3);
+    }
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn test_reject() {
+    let mut ring = ArraySliceRing::<u32, 8>::new();
+    assert_eq!(ring.push_many_back_with(&[0, 1, 2, 3, 4], Overflow::Reject), 5);
+    assert_eq!(ring.push_many_back_with(&[5, 6, 7, 8], Overflow::Reject), 2);
+    assert!(ring.is_full());
+    assert_eq!(contents(&ring), vec![0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn test_overwrite() {
+    let mut ring = ArraySliceRing::<u32, 8>::new();
+    ring.push_many_back(&[0, 1, 2, 3, 4]);
+    assert_eq!(ring.push_many_back_with(&[5, 6, 7, 8], Overflow::Overwrite), 4);
+    assert_eq!(contents(&ring), vec![2, 3, 4, 5, 6, 7, 8]);
+    let input = (10..30).collect::<Vec<u32>>();
+    assert_eq!(ring.push_many_back_with(&input[..], Overflow::Overwrite), 20);
+    assert_eq!(contents(&ring), (23..30).collect::<Vec<u32>>());
+
+    // the values count as pushed even if no value is kept
+    let mut ring = ArraySliceRing::<u32, 1>::new();
+    assert_eq!(ring.capacity(), 0);
+    assert_eq!(ring.push_many_back_with(&[1], Overflow::Overwrite), 1);
+    assert!(ring.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "array ring overflow")]
+fn test_push_many_back_overflow_panics() {
+    let mut ring = ArraySliceRing::<u32, 4>::new();
+    ring.push_many_back(&[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_try_push_many_back() {
+    let mut ring = ArraySliceRing::<u32, 4>::new();
+    assert_eq!(ring.try_push_many_back(&[0, 1]), Ok(()));
+    assert_eq!(ring.try_push_many_back(&[2, 3]), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(contents(&ring), vec![0, 1]);
+}
+
+#[test]
+fn test_drops_elements() {
+    let counters = Counters::new();
+    {
+        let input = counters.make(0..5);
+        let mut ring = ArraySliceRing::<DropCounter, 8>::new();
+        ring.push_many_back(&input[..]);
+        ring.drop_many_front(4);
+        assert_eq!(counters.drops(), 4);
+        ring.push_many_back_with(&input[..], Overflow::Overwrite);
+        assert_eq!(counters.drops(), 4);
+        ring.push_many_back_with(&input[..], Overflow::Overwrite);
+        assert_eq!(counters.drops(), 4 + 4);
+        assert_eq!(ring.len(), 7);
+    }
+    // the 7 elements in the ring and the 5 in `input`
+    assert_eq!(counters.drops(), 4 + 4 + 7 + 5);
+}
+
+#[test]
+fn test_zst() {
+    let mut ring = ArraySliceRing::<(), 16>::new();
+    ring.push_many_back(&[(); 10]);
+    assert_eq!(ring.drop_many_front(4), 4);
+    assert_eq!(ring.len(), 6);
+}
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::{ArraySliceRing, BoundedSliceRing, Overflow, SliceRing, SliceRingExt,
+              SliceRingImpl};
+
+mod common;
+use common::{Counters, DropCounter};
+
+macro_rules! test_both_ends {
+    ($new:expr) => {{
+        let mut ring = $new;
+        // wrap around the end of the buffer
+        ring.push_many_back(&[0; 12]);
+        ring.drop_many_front(12);
+        ring.push_many_back(&[4, 5, 6]);
+        ring.push_many_front(&[1, 2, 3]);
+        ring.push_many_front(&[]);
+        ring.push_many_front(&[0]);
+
+        let mut output = [0; 7];
+        assert_eq!(ring.read_many_front(&mut output), 7);
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6]);
+
+        let mut output = [-1; 3];
+        assert_eq!(ring.read_many_back(&mut output), 3);
+        assert_eq!(output, [4, 5, 6]);
+        let mut output = [-1; 9];
+        assert_eq!(ring.read_many_back(&mut output), 7);
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, -1, -1]);
+
+        assert_eq!(ring.drop_many_back(2), 2);
+        let mut output = [-1; 2];
+        assert_eq!(ring.read_many_back(&mut output), 2);
+        assert_eq!(output, [3, 4]);
+        assert_eq!(ring.drop_many_back(100), 5);
+        assert_eq!(ring.read_many_back(&mut output), 0);
+        assert_eq!(ring.drop_many_back(1), 0);
+
+        // the ring is still usable at both ends
+        ring.push_many_front(&[2, 3]);
+        ring.push_many_back(&[4]);
+        ring.push_many_front(&[1]);
+        let mut output = [0; 4];
+        assert_eq!(ring.read_many_front(&mut output), 4);
+        assert_eq!(output, [1, 2, 3, 4]);
+    }}
+}
+
+#[test]
+fn test_both_ends_deque() {
+    test_both_ends!(VecDeque::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_both_ends_optimized() {
+    test_both_ends!(SliceRingImpl::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_both_ends_bounded() {
+    test_both_ends!(BoundedSliceRing::<i32>::new(15, Overflow::Panic));
+}
+
+#[test]
+fn test_both_ends_array() {
+    test_both_ends!(ArraySliceRing::<i32, 16>::new());
+}
+
+#[test]
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+fn test_both_ends_mirrored() {
+    test_both_ends!(strider::MirroredSliceRing::<i32>::new().unwrap());
+}
+
+#[test]
+fn test_push_many_front_grows() {
+    for offset in 0..8 {
+        let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+        ring.push_many_back(&[0; 7][..offset]);
+        ring.drop_many_front(offset);
+        ring.push_many_back(&[5, 6]);
+        ring.push_many_front(&[3, 4]);
+        assert_eq!(ring.cap(), 8);
+
+        // fills the ring
+        ring.push_many_front(&[0, 1, 2]);
+        assert_eq!(ring.cap(), 8);
+        ring.push_many_front(&[-1]);
+        assert_eq!(ring.cap(), 16);
+        ring.push_many_back(&[7]);
+        ring.push_many_front(&(-20..-1).collect::<Vec<i32>>());
+        assert_eq!(ring.len(), 28);
+
+        let mut output = [0; 28];
+        assert_eq!(ring.read_many_front(&mut output), 28);
+        assert_eq!(output.to_vec(), (-20..8).collect::<Vec<i32>>());
+    }
+}
+
+#[test]
+fn test_drop_many_back_drops() {
+    let counters = Counters::new();
+    let values = counters.make(0..5);
+    let mut ring = SliceRingImpl::<DropCounter>::with_capacity(7);
+    ring.push_many_front(&values);
+    drop(values);
+    counters.reset();
+
+    assert_eq!(ring.drop_many_back(2), 2);
+    assert_eq!(counters.drops(), 2);
+    assert_eq!(ring.len(), 3);
+    drop(ring);
+    assert_eq!(counters.drops(), 5);
+}
+
+#[test]
+fn test_push_many_front_bounded_overflow() {
+    let mut ring = BoundedSliceRing::<i32>::new(4, Overflow::Reject);
+    ring.push_many_back(&[5, 6]);
+    // keeps the values closest to the old front
+    assert_eq!(ring.push_many_front_with(&[1, 2, 3, 4], Overflow::Reject), 2);
+    let mut output = [0; 4];
+    assert_eq!(ring.read_many_front(&mut output), 4);
+    assert_eq!(output, [3, 4, 5, 6]);
+
+    // drops from the back and keeps the first values
+    assert_eq!(ring.push_many_front_with(&[1, 2], Overflow::Overwrite), 2);
+    assert_eq!(ring.read_many_front(&mut output), 4);
+    assert_eq!(output, [1, 2, 3, 4]);
+    assert_eq!(ring.push_many_front_with(&[-2, -1, 0, 1, 2, 3], Overflow::Overwrite), 6);
+    assert_eq!(ring.read_many_front(&mut output), 4);
+    assert_eq!(output, [-2, -1, 0, 1]);
+}
+
+#[test]
+#[should_panic(expected = "bounded ring overflow")]
+fn test_push_many_front_bounded_panic() {
+    let mut ring = BoundedSliceRing::<i32>::new(4, Overflow::Panic);
+    ring.push_many_back(&[1, 2, 3]);
+    ring.push_many_front(&[1, 2]);
+}
+
+#[test]
+fn test_push_many_front_array_overflow() {
+    let mut ring = ArraySliceRing::<i32, 4>::new();
+    ring.push_many_back(&[5, 6]);
+    assert_eq!(ring.push_many_front_with(&[1, 2, 3, 4], Overflow::Reject), 1);
+    let mut output = [0; 3];
+    assert_eq!(ring.read_many_front(&mut output), 3);
+    assert_eq!(output, [4, 5, 6]);
+
+    assert_eq!(ring.push_many_front_with(&[1, 2], Overflow::Overwrite), 2);
+    assert_eq!(ring.read_many_front(&mut output), 3);
+    assert_eq!(output, [1, 2, 4]);
+}
+
+#[test]
+#[should_panic(expected = "array ring overflow")]
+fn test_push_many_front_array_panic() {
+    let mut ring = ArraySliceRing::<i32, 4>::new();
+    ring.push_many_back(&[1, 2]);
+    ring.push_many_front(&[1, 2]);
+}
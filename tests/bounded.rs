@@ -0,0 +1,118 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+#[macro_use]
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::{BoundedSliceRing, Overflow};
+use strider::TryReserveError;
+
+/// counts the allocations made by the current thread
+struct CountingAllocator;
+
+thread_local!(static ALLOCATIONS: Cell<usize> = const { Cell::new(0) });
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|allocations| allocations.get())
+}
+
+fn contents(ring: &BoundedSliceRing<u32>) -> Vec<u32> {
+    let mut output = vec![0; ring.len()];
+    ring.read_many_front(&mut output[..]);
+    output
+}
+
+#[test]
+fn test_slice_ring_bounded() {
+    test_slice_ring!(BoundedSliceRing::<i32>::new(6000, Overflow::Panic));
+}
+
+#[test]
+fn test_reject() {
+    let mut ring = BoundedSliceRing::new(5, Overflow::Reject);
+    assert_eq!(ring.capacity(), 5);
+    assert_eq!(ring.push_many_back_with(&[0, 1, 2], Overflow::Reject), 3);
+    assert_eq!(ring.free(), 2);
+    assert_eq!(ring.push_many_back_with(&[3, 4, 5, 6], Overflow::Reject), 2);
+    assert!(ring.is_full());
+    assert_eq!(contents(&ring), vec![0, 1, 2, 3, 4]);
+    assert_eq!(ring.push_many_back_with(&[7], Overflow::Reject), 0);
+
+    ring.drop_many_front(1);
+    ring.push_many_back(&[8, 9]);
+    assert_eq!(contents(&ring), vec![1, 2, 3, 4, 8]);
+}
+
+#[test]
+fn test_overwrite() {
+    let mut ring = BoundedSliceRing::new(5, Overflow::Overwrite);
+    ring.push_many_back(&[0, 1, 2]);
+    assert_eq!(ring.push_many_back_with(&[3, 4, 5, 6], Overflow::Overwrite), 4);
+    assert_eq!(contents(&ring), vec![2, 3, 4, 5, 6]);
+    ring.push_many_back(&[7]);
+    assert_eq!(contents(&ring), vec![3, 4, 5, 6, 7]);
+
+    // more values than fit. only the last ones are kept
+    ring.push_many_back(&(10..20).collect::<Vec<u32>>()[..]);
+    assert_eq!(contents(&ring), vec![15, 16, 17, 18, 19]);
+    assert_eq!(ring.len(), 5);
+}
+
+#[test]
+fn test_overwrite_zero_capacity() {
+    let mut ring = BoundedSliceRing::<u32>::new(0, Overflow::Overwrite);
+    // the values count as pushed and overwritten
+    assert_eq!(ring.push_many_back_with(&[1, 2], Overflow::Overwrite), 2);
+    assert_eq!(ring.push_many_front_with(&[1, 2], Overflow::Overwrite), 2);
+    assert!(ring.is_empty());
+    assert_eq!(ring.push_many_back_with(&[1, 2], Overflow::Reject), 0);
+}
+
+#[test]
+#[should_panic(expected = "bounded ring overflow")]
+fn test_panic() {
+    let mut ring = BoundedSliceRing::new(5, Overflow::Panic);
+    ring.push_many_back(&[0, 1, 2]);
+    ring.push_many_back(&[3, 4, 5]);
+}
+
+#[test]
+fn test_try_push_many_back() {
+    let mut ring = BoundedSliceRing::new(5, Overflow::Overwrite);
+    assert_eq!(ring.try_push_many_back(&[0, 1, 2]), Ok(()));
+    assert_eq!(ring.try_reserve(2), Ok(()));
+    assert_eq!(ring.try_reserve(3), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(ring.try_push_many_back(&[3, 4, 5]), Err(TryReserveError::CapacityOverflow));
+    assert_eq!(contents(&ring), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_never_allocates_after_construction() {
+    // the last second of 44100 hz audio
+    let mut ring = BoundedSliceRing::<f32>::new(44100, Overflow::Overwrite);
+    let input = vec![0.5; 1000];
+    let mut window = vec![0.0; 1024];
+
+    let before = allocations();
+    for _ in 0..200 {
+        ring.push_many_back(&input[..]);
+        ring.read_many_front(&mut window[..]);
+        ring.drop_many_front(100);
+    }
+    assert_eq!(allocations(), before);
+    assert_eq!(ring.len(), 44100 - 100);
+}
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::{ArraySliceRing, BoundedSliceRing, Overflow, SliceRing, SliceRingExt, SliceRingImpl};
+
+fn contents<R: SliceRingExt<i32>>(ring: &R) -> Vec<i32> {
+    let (front, back) = ring.read_slices_front(usize::MAX);
+    front.iter().chain(back).cloned().collect()
+}
+
+macro_rules! test_combine_many_at {
+    ($new:expr) => {{
+        let mut ring = $new;
+        // wrap around the end of the buffer
+        ring.push_many_back(&[0; 12]);
+        ring.drop_many_front(12);
+        ring.push_many_back(&[1, 2, 3, 4]);
+
+        // inside the ring
+        ring.combine_many_at(1, &[10, 20], |a, b| *a += *b);
+        assert_eq!(contents(&ring), vec![1, 12, 23, 4]);
+
+        // past the back the ring grows
+        ring.combine_many_at(3, &[100, 200, 300], |a, b| *a += *b);
+        assert_eq!(contents(&ring), vec![1, 12, 23, 104, 200, 300]);
+
+        // the gap between the back and the offset is filled with defaults
+        ring.combine_many_at(8, &[5], |a, b| *a -= *b);
+        assert_eq!(contents(&ring), vec![1, 12, 23, 104, 200, 300, 0, 0, -5]);
+
+        // any combination
+        ring.combine_many_at(0, &[7, 7], |a, b| *a = (*a).max(*b));
+        assert_eq!(contents(&ring), vec![7, 12, 23, 104, 200, 300, 0, 0, -5]);
+
+        // nothing to combine
+        ring.combine_many_at(2, &[], |_, _| unreachable!());
+        assert_eq!(contents(&ring).len(), 9);
+    }}
+}
+
+#[test]
+fn test_combine_many_at_deque() {
+    test_combine_many_at!(VecDeque::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_combine_many_at_optimized() {
+    test_combine_many_at!(SliceRingImpl::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_combine_many_at_bounded() {
+    test_combine_many_at!(BoundedSliceRing::<i32>::new(15, Overflow::Overwrite));
+}
+
+#[test]
+fn test_combine_many_at_array() {
+    test_combine_many_at!(ArraySliceRing::<i32, 16>::new());
+}
+
+#[test]
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+fn test_combine_many_at_mirrored() {
+    test_combine_many_at!(strider::MirroredSliceRing::<i32>::new().unwrap());
+}
+
+#[test]
+fn test_combine_many_at_grows_capacity() {
+    let mut ring = SliceRingImpl::<i32>::new();
+    let input = (0..1000).collect::<Vec<i32>>();
+    ring.combine_many_at(500, &input[..], |a, b| *a += *b);
+    assert_eq!(ring.len(), 1500);
+    ring.combine_many_at(0, &input[..], |a, b| *a += *b);
+    let values = contents(&ring);
+    assert_eq!(values[499], 499);
+    assert_eq!(values[500], 500);
+    assert_eq!(values[999], 999 + 499);
+    assert_eq!(values[1499], 999);
+}
+
+#[test]
+#[should_panic(expected = "bounded ring overflow")]
+fn test_combine_many_at_bounded_overflow() {
+    let mut ring = BoundedSliceRing::<i32>::new(4, Overflow::Overwrite);
+    ring.combine_many_at(2, &[1, 2, 3], |a, b| *a += *b);
+}
+
+#[test]
+#[should_panic(expected = "array ring overflow")]
+fn test_combine_many_at_array_overflow() {
+    let mut ring = ArraySliceRing::<i32, 4>::new();
+    ring.combine_many_at(2, &[1, 2], |a, b| *a += *b);
+}
@@ -0,0 +1,93 @@
+extern crate strider;
+use strider::{SliceRing, SliceRingImpl};
+
+mod common;
+use common::{Counters, DropCounter};
+
+#[test]
+fn test_write_slices_back_wrapped() {
+    let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+    ring.push_many_back(&[0; 5]);
+    ring.drop_many_front(5);
+    ring.push_many_back(&[1]);
+
+    {
+        let (front, back) = ring.write_slices_back(4);
+        // the free space wraps around the end of the buffer
+        assert_eq!(front.len(), 2);
+        assert_eq!(back.len(), 2);
+        for (slot, value) in front.iter_mut().chain(back.iter_mut()).zip(2..) {
+            slot.write(value);
+        }
+    }
+    assert_eq!(ring.len(), 1);
+    unsafe { ring.commit_back(3) };
+    assert_eq!(ring.cap(), 8);
+    assert_eq!(ring.len(), 4);
+    assert!(!ring.is_continuous());
+    assert_eq!(ring.read_slices_front(4), (&[1, 2, 3][..], &[4][..]));
+
+    // the uncommitted element is overwritten by the next push
+    ring.push_many_back(&[5]);
+    assert_eq!(ring.read_slices_front(5), (&[1, 2, 3][..], &[4, 5][..]));
+}
+
+#[test]
+fn test_write_slices_back_grows() {
+    for offset in 0..8 {
+        let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+        ring.push_many_back(&[0; 7][..offset]);
+        ring.drop_many_front(offset);
+        ring.push_many_back(&[0, 1, 2, 3, 4]);
+
+        {
+            let (front, back) = ring.write_slices_back(10);
+            assert_eq!(front.len() + back.len(), 10);
+            for (slot, value) in front.iter_mut().chain(back.iter_mut()).zip(5..) {
+                slot.write(value);
+            }
+        }
+        unsafe { ring.commit_back(10) };
+        assert_eq!(ring.cap(), 16);
+
+        let mut output = [0; 15];
+        assert_eq!(ring.read_many_front(&mut output), 15);
+        assert_eq!(output.to_vec(), (0..15).collect::<Vec<i32>>());
+    }
+}
+
+#[test]
+fn test_write_slices_back_empty() {
+    let mut ring = SliceRingImpl::<i32>::new();
+    {
+        let (front, back) = ring.write_slices_back(0);
+        assert!(front.is_empty());
+        assert!(back.is_empty());
+    }
+    unsafe { ring.commit_back(0) };
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn test_commit_back_drops() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::<DropCounter>::new();
+    {
+        let (front, back) = ring.write_slices_back(3);
+        for (slot, counter) in front.iter_mut().chain(back.iter_mut()).zip(counters.make(0..2)) {
+            slot.write(counter);
+        }
+    }
+    unsafe { ring.commit_back(2) };
+    assert_eq!(ring.len(), 2);
+    // committed elements are owned by the ring
+    drop(ring);
+    assert_eq!(counters.drops(), 2);
+}
+
+#[test]
+#[should_panic(expected = "committing 8 elements with only 7 free")]
+fn test_commit_back_more_than_free() {
+    let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+    unsafe { ring.commit_back(8) };
+}
@@ -0,0 +1,82 @@
+//! fixtures shared by the integration tests.
+//! every test crate uses only some of them.
+#![allow(dead_code)]
+
+use std::ops::Range;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// counts how often the [DropCounter](struct.DropCounter.html)s
+/// it made are cloned and dropped.
+/// `Send` so the counters can move between threads.
+#[derive(Clone, Default)]
+pub struct Counters {
+    clones: Arc<AtomicUsize>,
+    drops: Arc<AtomicUsize>,
+}
+
+impl Counters {
+    pub fn new() -> Counters {
+        Counters::default()
+    }
+
+    /// one `DropCounter` for each id
+    pub fn make(&self, ids: Range<i32>) -> Vec<DropCounter> {
+        ids.map(|id| DropCounter { id, counters: self.clone() }).collect()
+    }
+
+    pub fn clones(&self) -> usize {
+        self.clones.load(Ordering::SeqCst)
+    }
+
+    pub fn drops(&self) -> usize {
+        self.drops.load(Ordering::SeqCst)
+    }
+
+    pub fn reset(&self) {
+        self.clones.store(0, Ordering::SeqCst);
+        self.drops.store(0, Ordering::SeqCst);
+    }
+}
+
+/// increments the shared counts of its
+/// [Counters](struct.Counters.html) when it is cloned or dropped
+pub struct DropCounter {
+    pub id: i32,
+    counters: Counters,
+}
+
+impl Clone for DropCounter {
+    fn clone(&self) -> DropCounter {
+        self.counters.clones.fetch_add(1, Ordering::SeqCst);
+        DropCounter { id: self.id, counters: self.counters.clone() }
+    }
+}
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.counters.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+pub fn ids(counters: &[DropCounter]) -> Vec<i32> {
+    counters.iter().map(|counter| counter.id).collect()
+}
+
+/// xorshift so the randomized tests are reproducible
+/// and need no dependencies
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// a number in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next() % (n as u64)) as usize
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingImpl;
+
+mod common;
+use common::Rng;
+
+/// ring with capacity 7 whose elements wrap around after
+/// pushing `head_len + tail_len` and dropping some
+fn wrapped(head_len: usize, tail_len: usize) -> SliceRingImpl<String> {
+    let mut ring = SliceRingImpl::with_capacity(7);
+    let first_readable = 8 - head_len;
+    let input = (0..first_readable + head_len + tail_len)
+        .map(|i| i.to_string()).collect::<Vec<String>>();
+    ring.push_many_back(&input[..first_readable]);
+    ring.drop_many_front(first_readable);
+    ring.push_many_back(&input[first_readable..]);
+    assert_eq!(ring.cap(), 8);
+    assert!(!ring.is_continuous());
+    ring
+}
+
+fn expected(head_len: usize, tail_len: usize) -> Vec<String> {
+    (8 - head_len..8 + tail_len).map(|i| i.to_string()).collect()
+}
+
+#[test]
+fn test_make_contiguous_head_fits_into_free_space() {
+    let mut ring = wrapped(3, 2);
+    assert_eq!(ring.make_contiguous().to_vec(), expected(3, 2));
+    assert_eq!(ring.rotations(), 1);
+    assert!(ring.is_continuous());
+    assert_eq!(ring.len(), 5);
+}
+
+#[test]
+fn test_make_contiguous_tail_fits_into_free_space() {
+    let mut ring = wrapped(5, 1);
+    assert_eq!(ring.make_contiguous().to_vec(), expected(5, 1));
+    assert_eq!(ring.rotations(), 1);
+    let (first, second) = ring.read_slices_front(6);
+    assert_eq!(first.len(), 6);
+    assert!(second.is_empty());
+}
+
+#[test]
+fn test_make_contiguous_rotates() {
+    let mut ring = wrapped(4, 3);
+    assert_eq!(ring.make_contiguous().to_vec(), expected(4, 3));
+    assert_eq!(ring.rotations(), 1);
+    // still works as a ring afterwards
+    ring.drop_many_front(6);
+    ring.push_many_back(&["a".to_string(), "b".to_string()]);
+    let mut output = vec![String::new(); 3];
+    assert_eq!(ring.read_many_front(&mut output[..]), 3);
+    assert_eq!(output, vec!["10", "a", "b"]);
+}
+
+#[test]
+fn test_make_contiguous_does_nothing_if_continuous() {
+    let mut ring = SliceRingImpl::<i32>::new();
+    ring.push_many_back(&[1, 2, 3]);
+    assert_eq!(ring.make_contiguous(), &mut [1, 2, 3]);
+    assert_eq!(ring.rotations(), 0);
+}
+
+#[test]
+fn test_contiguous_front_mut_only_rotates_when_straddling() {
+    let mut ring = wrapped(3, 4);
+    // the window ends before the wrap point
+    assert_eq!(ring.contiguous_front_mut(3).to_vec(), expected(3, 0));
+    assert_eq!(ring.rotations(), 0);
+    // the window straddles the wrap point
+    assert_eq!(ring.contiguous_front_mut(5).to_vec(), expected(3, 2));
+    assert_eq!(ring.rotations(), 1);
+    // elements can be modified in place
+    ring.contiguous_front_mut(1)[0].push('!');
+    assert_eq!(ring.contiguous_front_mut(100).to_vec()[..2], ["5!", "6"]);
+    assert_eq!(ring.rotations(), 1);
+}
+
+#[test]
+fn test_make_contiguous_zst() {
+    let mut ring = SliceRingImpl::<()>::new();
+    ring.push_many_back(&[(); 10]);
+    ring.drop_many_front(3);
+    assert_eq!(ring.make_contiguous().len(), 7);
+    assert_eq!(ring.contiguous_front_mut(5).len(), 5);
+}
+
+#[test]
+fn test_contiguous_front_mut_differential() {
+    for seed in 1..100 {
+        let mut rng = Rng(seed);
+        let mut ring = SliceRingImpl::<String>::with_capacity(rng.below(20));
+        let mut deque = VecDeque::<String>::new();
+        let mut next_value = 0;
+        for _ in 0..100 {
+            let input = (next_value..next_value + rng.below(20))
+                .map(|i| i.to_string()).collect::<Vec<String>>();
+            next_value += input.len();
+            ring.push_many_back(&input[..]);
+            deque.push_many_back(&input[..]);
+
+            let count = rng.below(20);
+            let window = ring.contiguous_front_mut(count).to_vec();
+            assert_eq!(window, deque.iter().take(count).cloned().collect::<Vec<String>>());
+
+            let count = rng.below(20);
+            assert_eq!(ring.drop_many_front(count), deque.drop_many_front(count));
+        }
+        assert_eq!(ring.make_contiguous().to_vec(), deque.iter().cloned().collect::<Vec<String>>());
+    }
+}
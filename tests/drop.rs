@@ -0,0 +1,166 @@
+use std::cell::Cell;
+use std::panic;
+use std::rc::Rc;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingImpl;
+
+mod common;
+use common::Counters;
+
+#[test]
+fn test_drop_many_front_drops_elements() {
+    let counters = Counters::new();
+    let input = counters.make(0..5);
+    let mut ring = SliceRingImpl::new();
+    ring.push_many_back(&input[..]);
+    drop(input);
+    assert_eq!(counters.drops(), 5);
+
+    assert_eq!(ring.drop_many_front(3), 3);
+    assert_eq!(counters.drops(), 8);
+    assert_eq!(ring.drop_many_front(10), 2);
+    assert_eq!(counters.drops(), 10);
+    assert_eq!(ring.drop_many_front(10), 0);
+    assert_eq!(counters.drops(), 10);
+}
+
+#[test]
+fn test_drop_many_front_wrapped() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::with_capacity(7);
+    ring.push_many_back(&counters.make(0..7)[..]);
+    assert_eq!(counters.drops(), 7);
+    ring.drop_many_front(5);
+    ring.push_many_back(&counters.make(0..4)[..]);
+    assert_eq!(counters.drops(), 7 + 5 + 4);
+    assert!(!ring.is_continuous());
+    assert_eq!(ring.cap(), 8);
+
+    // drops the part at the end of the buffer
+    // and the part at the start of the buffer
+    assert_eq!(ring.drop_many_front(5), 5);
+    assert_eq!(counters.drops(), 7 + 5 + 4 + 5);
+    assert_eq!(ring.len(), 1);
+}
+
+#[test]
+fn test_clear_drops_elements() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::new();
+    ring.push_many_back(&counters.make(0..20)[..]);
+    assert_eq!(counters.drops(), 20);
+    ring.clear();
+    assert_eq!(counters.drops(), 40);
+    assert_eq!(ring.len(), 0);
+    assert!(ring.is_empty());
+
+    ring.push_many_back(&counters.make(0..3)[..]);
+    assert_eq!(ring.len(), 3);
+    drop(ring);
+    assert_eq!(counters.drops(), 46);
+}
+
+#[test]
+fn test_drop_ring_drops_elements() {
+    let counters = Counters::new();
+    {
+        let mut ring = SliceRingImpl::with_capacity(7);
+        ring.push_many_back(&counters.make(0..7)[..]);
+        ring.drop_many_front(6);
+        ring.push_many_back(&counters.make(0..6)[..]);
+        assert!(!ring.is_continuous());
+        assert_eq!(counters.drops(), 7 + 6 + 6);
+    }
+    assert_eq!(counters.drops(), 7 + 6 + 6 + 7);
+}
+
+#[test]
+fn test_growth_does_not_drop_elements() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::with_capacity(7);
+    ring.push_many_back(&counters.make(0..7)[..]);
+    ring.drop_many_front(5);
+    ring.push_many_back(&counters.make(0..3)[..]);
+    assert_eq!(counters.drops(), 7 + 5 + 3);
+    ring.push_many_back(&counters.make(0..100)[..]);
+    assert_eq!(counters.drops(), 7 + 5 + 3 + 100);
+    assert_eq!(ring.len(), 105);
+    drop(ring);
+    assert_eq!(counters.drops(), 7 + 5 + 3 + 100 + 105);
+}
+
+#[test]
+fn test_read_many_front_drops_overwritten_output() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::new();
+    ring.push_many_back(&counters.make(0..4)[..]);
+    let mut output = counters.make(0..2);
+    assert_eq!(counters.drops(), 4);
+    assert_eq!(ring.read_many_front(&mut output[..]), 2);
+    // the previous contents of `output` were dropped
+    assert_eq!(counters.drops(), 6);
+    drop(output);
+    drop(ring);
+    assert_eq!(counters.drops(), 12);
+}
+
+/// panics in `clone` once a shared budget of clones is used up
+struct PanicOnClone {
+    clones_left: Rc<Cell<usize>>,
+    drops: Rc<Cell<usize>>,
+}
+
+impl Clone for PanicOnClone {
+    fn clone(&self) -> PanicOnClone {
+        if self.clones_left.get() == 0 {
+            panic!("clone budget exhausted");
+        }
+        self.clones_left.set(self.clones_left.get() - 1);
+        PanicOnClone {
+            clones_left: self.clones_left.clone(),
+            drops: self.drops.clone(),
+        }
+    }
+}
+
+impl Drop for PanicOnClone {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+#[test]
+fn test_push_many_back_panic_safety() {
+    let clones_left = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let input = (0..10).map(|_| PanicOnClone {
+        clones_left: clones_left.clone(),
+        drops: drops.clone(),
+    }).collect::<Vec<PanicOnClone>>();
+
+    let mut ring = SliceRingImpl::with_capacity(7);
+    clones_left.set(6);
+    ring.push_many_back(&input[..6]);
+    ring.drop_many_front(4);
+    assert_eq!(drops.get(), 4);
+
+    // the push wraps around and panics after cloning 3 of 5 elements
+    clones_left.set(3);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        ring.push_many_back(&input[..5]);
+    }));
+    assert!(result.is_err());
+    assert!(!ring.is_continuous());
+    // the elements cloned before the panic are part of the ring
+    assert_eq!(ring.len(), 2 + 3);
+    assert_eq!(drops.get(), 4);
+
+    assert_eq!(ring.drop_many_front(3), 3);
+    assert_eq!(drops.get(), 7);
+    drop(ring);
+    assert_eq!(drops.get(), 9);
+    drop(input);
+    assert_eq!(drops.get(), 19);
+}
@@ -0,0 +1,81 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::SliceRingImpl;
+use strider::TryReserveError;
+
+macro_rules! test_try_push_many_back {
+    ($new:expr) => {{
+        let mut ring = $new;
+        let input = (0..100).collect::<Vec<u8>>();
+        assert_eq!(ring.try_push_many_back(&input[..]), Ok(()));
+        assert_eq!(ring.len(), 100);
+        assert_eq!(ring.drop_many_front(40), 40);
+        assert_eq!(ring.try_push_many_back(&input[..]), Ok(()));
+        assert_eq!(ring.len(), 160);
+        let mut output = vec![0; 160];
+        assert_eq!(ring.read_many_front(&mut output[..]), 160);
+        assert_eq!(output, (40..100).chain(0..100).collect::<Vec<u8>>());
+    }}
+}
+#[test]
+fn test_try_push_many_back_deque() {
+    test_try_push_many_back!(VecDeque::<u8>::new());
+}
+#[test]
+fn test_try_push_many_back_optimized() {
+    test_try_push_many_back!(SliceRingImpl::<u8>::new());
+}
+
+macro_rules! test_try_reserve_errors {
+    ($new:expr) => {{
+        let mut ring = $new;
+        ring.push_many_back(&[1, 2, 3]);
+
+        assert_eq!(SliceRingExt::try_reserve(&mut ring, usize::MAX),
+                   Err(TryReserveError::CapacityOverflow));
+        assert_eq!(SliceRingExt::try_reserve(&mut ring, usize::MAX / 8),
+                   Err(TryReserveError::CapacityOverflow));
+        // no allocator can satisfy this.
+        // a quarter of the largest possible allocation still fits after
+        // rounding up to a power of two. on 32 bit targets it might be served
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(SliceRingExt::try_reserve(
+                       &mut ring, isize::MAX as usize / std::mem::size_of::<u32>() / 4),
+                   Err(TryReserveError::AllocError));
+
+        // the ring is unchanged
+        assert_eq!(ring.len(), 3);
+        let mut output = [0; 3];
+        assert_eq!(ring.read_many_front(&mut output[..]), 3);
+        assert_eq!(output, [1, 2, 3]);
+    }}
+}
+#[test]
+fn test_try_reserve_errors_deque() {
+    test_try_reserve_errors!(VecDeque::<u32>::new());
+}
+#[test]
+fn test_try_reserve_errors_optimized() {
+    test_try_reserve_errors!(SliceRingImpl::<u32>::new());
+}
+
+#[test]
+fn test_try_reserve_avoids_later_allocation() {
+    let mut ring = SliceRingImpl::<u32>::new();
+    ring.push_many_back(&[1, 2, 3]);
+    assert_eq!(ring.try_reserve(1000), Ok(()));
+    let capacity = ring.capacity();
+    assert!(capacity >= 1003);
+    ring.push_many_back(&(0..1000).collect::<Vec<u32>>()[..]);
+    assert_eq!(ring.capacity(), capacity);
+    assert_eq!(ring.len(), 1003);
+}
+
+#[test]
+fn test_try_reserve_error_display() {
+    assert_eq!(TryReserveError::CapacityOverflow.to_string(), "capacity overflow");
+    assert_eq!(TryReserveError::AllocError.to_string(), "memory allocation failed");
+}
@@ -0,0 +1,117 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingImpl;
+
+mod common;
+use common::Rng;
+
+/// reads all elements present in `ring` without removing them
+fn contents(ring: &SliceRingImpl<u32>) -> Vec<u32> {
+    let mut output: Vec<u32> = vec![0; ring.len()];
+    assert_eq!(ring.read_many_front(&mut output[..]), ring.len());
+    output
+}
+
+#[test]
+fn test_grow_continuous() {
+    let mut ring = SliceRingImpl::<u32>::with_capacity(7);
+    ring.push_many_back(&[0, 1, 2, 3, 4]);
+    assert!(ring.is_continuous());
+    ring.push_many_back(&[5, 6, 7, 8]);
+    assert_eq!(ring.cap(), 16);
+    assert_eq!(contents(&ring), (0..9).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_grow_wrapped_shortest_section_at_front() {
+    let mut ring = SliceRingImpl::<u32>::with_capacity(7);
+    assert_eq!(ring.cap(), 8);
+    ring.push_many_back(&[0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(ring.drop_many_front(5), 5);
+    ring.push_many_back(&[7, 8, 9]);
+    //      W     R
+    // [o o . . . o o o ]
+    assert!(!ring.is_continuous());
+
+    ring.push_many_back(&[10, 11, 12, 13]);
+    assert_eq!(ring.cap(), 16);
+    assert!(ring.is_continuous());
+    assert_eq!(contents(&ring), (5..14).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_grow_wrapped_shortest_section_at_tail() {
+    let mut ring = SliceRingImpl::<u32>::with_capacity(7);
+    ring.push_many_back(&[0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(ring.drop_many_front(6), 6);
+    ring.push_many_back(&[7, 8, 9, 10, 11, 12]);
+    //            W R
+    // [o o o o o . o o ]
+    assert!(!ring.is_continuous());
+
+    ring.push_many_back(&[13]);
+    assert_eq!(ring.cap(), 16);
+    assert!(!ring.is_continuous());
+    assert_eq!(contents(&ring), (6..14).collect::<Vec<u32>>());
+
+    // growing again from the new layout
+    ring.push_many_back(&(14..40).collect::<Vec<u32>>()[..]);
+    assert_eq!(contents(&ring), (6..40).collect::<Vec<u32>>());
+}
+
+/// drives a `SliceRingImpl` and a `VecDeque` through the same
+/// random sequence of pushes, reads and drops
+/// and compares their contents after every operation
+fn run_differential(seed: u64, steps: usize, max_chunk: usize) {
+    let mut rng = Rng(seed);
+    let mut ring = SliceRingImpl::<u32>::with_capacity(rng.below(16));
+    let mut deque = VecDeque::<u32>::new();
+    let mut next_value = 0;
+
+    for step in 0..steps {
+        match rng.below(3) {
+            0 => {
+                let input = (next_value..next_value + rng.below(max_chunk) as u32)
+                    .collect::<Vec<u32>>();
+                next_value += input.len() as u32;
+                ring.push_many_back(&input[..]);
+                deque.push_many_back(&input[..]);
+            }
+            1 => {
+                let count = rng.below(max_chunk);
+                assert_eq!(ring.drop_many_front(count), deque.drop_many_front(count),
+                           "seed={} step={}", seed, step);
+            }
+            _ => {
+                let count = rng.below(max_chunk);
+                let mut ring_output: Vec<u32> = vec![0; count];
+                let mut deque_output = ring_output.clone();
+                assert_eq!(ring.read_many_front(&mut ring_output[..]),
+                           deque.read_many_front(&mut deque_output[..]),
+                           "seed={} step={}", seed, step);
+                assert_eq!(ring_output, deque_output, "seed={} step={}", seed, step);
+            }
+        }
+        assert_eq!(ring.len(), deque.len(), "seed={} step={}", seed, step);
+        assert!(ring.len() <= ring.capacity());
+        assert!(ring.cap().is_power_of_two());
+        assert_eq!(contents(&ring), deque.iter().cloned().collect::<Vec<u32>>(),
+                   "seed={} step={}", seed, step);
+    }
+}
+
+#[test]
+fn test_differential_small_chunks() {
+    for seed in 1..200 {
+        run_differential(seed, 200, 10);
+    }
+}
+
+#[test]
+fn test_differential_large_chunks() {
+    for seed in 1..50 {
+        run_differential(seed, 200, 300);
+    }
+}
@@ -0,0 +1,380 @@
+#![cfg(feature = "std")]
+
+use std::collections::VecDeque;
+use std::io;
+use std::io::{BufRead, IoSlice, Read, Write};
+
+extern crate strider;
+use strider::{SliceRing, SliceRingImpl};
+
+macro_rules! test_read_write {
+    ($new:expr) => {{
+        let mut ring = $new;
+        // wrap around the end of the buffer
+        ring.write_all(&[0; 12]).unwrap();
+        let mut skipped = [0; 12];
+        ring.read_exact(&mut skipped).unwrap();
+
+        assert_eq!(ring.write(b"ABCDEF").unwrap(), 6);
+        assert_eq!(ring.write_vectored(&[IoSlice::new(b"GH"), IoSlice::new(b"I")]).unwrap(), 3);
+        ring.flush().unwrap();
+        assert_eq!(ring.len(), 9);
+
+        let mut output = [0; 4];
+        assert_eq!(ring.read(&mut output).unwrap(), 4);
+        assert_eq!(&output, b"ABCD");
+        assert_eq!(ring.len(), 5);
+
+        let mut rest = Vec::new();
+        assert_eq!(ring.read_to_end(&mut rest).unwrap(), 5);
+        assert_eq!(rest, b"EFGHI");
+        assert_eq!(ring.read(&mut output).unwrap(), 0);
+        assert_eq!(&output, b"ABCD");
+    }}
+}
+
+#[test]
+fn test_read_write_deque() {
+    test_read_write!(VecDeque::<u8>::with_capacity(15));
+}
+
+#[test]
+fn test_read_write_optimized() {
+    test_read_write!(SliceRingImpl::<u8>::with_capacity(15));
+}
+
+macro_rules! test_buf_read {
+    ($new:expr) => {{
+        let mut ring = $new;
+        ring.write_all(b"first line\nsecond").unwrap();
+
+        let mut line = String::new();
+        assert_eq!(ring.read_line(&mut line).unwrap(), 11);
+        assert_eq!(line, "first line\n");
+
+        ring.write_all(b" line\nthird").unwrap();
+        let lines: Vec<String> = ring.lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["second line", "third"]);
+    }}
+}
+
+#[test]
+fn test_buf_read_deque() {
+    test_buf_read!(VecDeque::<u8>::new());
+}
+
+#[test]
+fn test_buf_read_optimized() {
+    test_buf_read!(SliceRingImpl::<u8>::new());
+}
+
+#[test]
+fn test_fill_buf_returns_first_contiguous_part() {
+    let mut ring = SliceRingImpl::<u8>::with_capacity(7);
+    ring.push_many_back(&[0; 5]);
+    ring.drop_many_front(5);
+    ring.write_all(b"ABCDE").unwrap();
+    assert!(!ring.is_continuous());
+
+    assert_eq!(ring.fill_buf().unwrap(), b"ABC");
+    ring.consume(2);
+    assert_eq!(ring.fill_buf().unwrap(), b"C");
+    ring.consume(1);
+    assert_eq!(ring.fill_buf().unwrap(), b"DE");
+    ring.consume(100);
+    assert!(ring.is_empty());
+    assert_eq!(ring.fill_buf().unwrap(), b"");
+}
+
+#[test]
+fn test_copy_between_rings() {
+    let mut input = SliceRingImpl::<u8>::new();
+    input.write_all(&(0..=255).collect::<Vec<u8>>()).unwrap();
+    let mut output = SliceRingImpl::<u8>::with_capacity(3);
+    assert_eq!(io::copy(&mut input, &mut output).unwrap(), 256);
+    assert!(input.is_empty());
+    assert_eq!(output.len(), 256);
+
+    let mut all = [0; 256];
+    output.read_many_front(&mut all);
+    assert_eq!(all.to_vec(), (0..=255).collect::<Vec<u8>>());
+}
+
+macro_rules! test_copy_windowing {
+    ($new:expr) => {{
+        const WINDOW_SIZE: usize = 8;
+        const STEP_SIZE: usize = 2;
+        let mut input = io::Cursor::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        let mut output = Vec::<u8>::new();
+        let mut ring = $new;
+        let window_buffer: &mut [u8] = &mut [0; WINDOW_SIZE];
+
+        loop {
+            // the ring is the writer so no input buffer is needed
+            let input_count = io::copy(&mut (&mut input).take(4), &mut ring).unwrap();
+            if input_count == 0 { break; }
+
+            while WINDOW_SIZE <= ring.len() {
+                ring.read_many_front(window_buffer);
+                output.write_all(window_buffer).unwrap();
+                ring.drop_many_front(STEP_SIZE);
+            }
+        }
+        assert_eq!(String::from_utf8(output).unwrap(),
+                   "ABCDEFGHCDEFGHIJEFGHIJKLGHIJKLMNIJKLMNOPKLMNOPQRMNOPQRSTOPQRSTUVQRSTUVWXSTUVWXYZ");
+    }}
+}
+
+#[test]
+fn test_copy_windowing_deque() {
+    test_copy_windowing!(VecDeque::<u8>::new());
+}
+
+#[test]
+fn test_copy_windowing_optimized() {
+    test_copy_windowing!(SliceRingImpl::<u8>::new());
+}
+
+/// reads `chunks` one per call and fails with `Interrupted` before each.
+/// only fills the first buffer of a vectored read
+/// unless `vectored` is set.
+/// what doesn't fit is read by the next call.
+struct ChunkReader {
+    chunks: Vec<&'static [u8]>,
+    interrupt: bool,
+    vectored: bool,
+}
+
+impl ChunkReader {
+    fn new(chunks: Vec<&'static [u8]>, vectored: bool) -> ChunkReader {
+        ChunkReader { chunks, interrupt: true, vectored }
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_vectored(&mut [io::IoSliceMut::new(buf)])
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+        if self.interrupt {
+            self.interrupt = false;
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        self.interrupt = true;
+        if self.chunks.is_empty() {
+            return Ok(0);
+        }
+        let mut chunk = self.chunks.remove(0);
+        let bufs = if self.vectored { &mut bufs[..] } else { &mut bufs[..1] };
+        let mut read = 0;
+        for buf in bufs {
+            let count = std::cmp::min(buf.len(), chunk.len());
+            buf[..count].copy_from_slice(&chunk[..count]);
+            chunk = &chunk[count..];
+            read += count;
+        }
+        if !chunk.is_empty() {
+            self.chunks.insert(0, chunk);
+        }
+        Ok(read)
+    }
+}
+
+#[test]
+fn test_fill_from() {
+    let mut ring = SliceRingImpl::<u8>::new();
+    let mut reader = ChunkReader::new(vec![b"ABC", b"", b"DEFGHIJKLMNOPQRSTUVWXYZ"], false);
+    assert_eq!(ring.fill_from(&mut reader, 3).unwrap(), 3);
+    // an empty read ends the stream
+    assert_eq!(ring.fill_from(&mut reader, 3).unwrap(), 0);
+    // grows the ring
+    assert_eq!(ring.fill_from(&mut reader, 100).unwrap(), 23);
+    assert_eq!(ring.fill_from(&mut reader, 100).unwrap(), 0);
+    assert_eq!(ring.fill_from(&mut reader, 0).unwrap(), 0);
+
+    let mut output = Vec::new();
+    ring.read_to_end(&mut output).unwrap();
+    assert_eq!(output, b"ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+}
+
+#[test]
+fn test_fill_from_wrapped() {
+    for &vectored in &[false, true] {
+        let mut ring = SliceRingImpl::<u8>::with_capacity(7);
+        ring.push_many_back(&[0; 5]);
+        ring.drop_many_front(5);
+        ring.push_many_back(b"A");
+        let cap = ring.cap();
+
+        // the free space wraps around the end of the buffer
+        let mut reader = ChunkReader::new(vec![b"BCDEF"], vectored);
+        let read = ring.fill_from(&mut reader, 6).unwrap();
+        if vectored {
+            assert_eq!(read, 5);
+        } else {
+            // only filled the part before the end of the buffer
+            assert_eq!(read, 2);
+            assert_eq!(ring.fill_from(&mut reader, 4).unwrap(), 3);
+        }
+        assert_eq!(ring.cap(), cap);
+        assert!(!ring.is_continuous());
+        let mut output = Vec::new();
+        ring.read_to_end(&mut output).unwrap();
+        assert_eq!(output, b"ABCDEF");
+    }
+}
+
+#[test]
+fn test_fill_from_zeroes_once() {
+    /// remembers the buffers it was handed and reads nothing
+    struct Peek(Vec<u8>);
+
+    impl Read for Peek {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.read_vectored(&mut [io::IoSliceMut::new(buf)])
+        }
+
+        fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+            self.0 = bufs.iter().flat_map(|buf| buf.iter().cloned()).collect();
+            Ok(0)
+        }
+    }
+
+    let mut ring = SliceRingImpl::<u8>::with_capacity(7);
+    let mut peek = Peek(Vec::new());
+    assert_eq!(ring.fill_from(&mut peek, 4).unwrap(), 0);
+    assert_eq!(peek.0, [0, 0, 0, 0]);
+
+    // bytes that were dropped are handed out again as they are
+    ring.push_many_back(b"ABCD");
+    ring.drop_many_front(4);
+    ring.push_many_back(b"EF");
+    ring.drop_many_front(2);
+    assert_eq!(ring.fill_from(&mut peek, 4).unwrap(), 0);
+    assert_eq!(peek.0, b"\0\0AB");
+}
+
+#[test]
+fn test_fill_from_reader_fails() {
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+    }
+
+    let mut ring = SliceRingImpl::<u8>::new();
+    ring.push_many_back(b"AB");
+    let err = ring.fill_from(&mut Failing, 4).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(ring.len(), 2);
+}
+
+/// accepts at most `limit` bytes per call
+/// and fails with `Interrupted` before each.
+/// only writes the first buffer of a vectored write
+/// unless `vectored` is set.
+struct ShortWriter {
+    written: Vec<u8>,
+    limit: usize,
+    interrupt: bool,
+    vectored: bool,
+}
+
+impl ShortWriter {
+    fn new(limit: usize, vectored: bool) -> ShortWriter {
+        ShortWriter { written: Vec::new(), limit, interrupt: true, vectored }
+    }
+}
+
+impl Write for ShortWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)])
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        if self.interrupt {
+            self.interrupt = false;
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        self.interrupt = true;
+        let bufs = if self.vectored { bufs } else { &bufs[..1] };
+        let mut written = 0;
+        for buf in bufs {
+            let count = std::cmp::min(buf.len(), self.limit - written);
+            self.written.extend_from_slice(&buf[..count]);
+            written += count;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_to() {
+    let mut ring = SliceRingImpl::<u8>::new();
+    ring.push_many_back(b"ABCDEFGHIJ");
+    let mut writer = ShortWriter::new(4, false);
+
+    // short writes
+    assert_eq!(ring.write_to(&mut writer, 100).unwrap(), 4);
+    assert_eq!(ring.write_to(&mut writer, 3).unwrap(), 3);
+    assert_eq!(ring.len(), 3);
+    assert_eq!(ring.write_to(&mut writer, 100).unwrap(), 3);
+    assert!(ring.is_empty());
+    assert_eq!(ring.write_to(&mut writer, 100).unwrap(), 0);
+    assert_eq!(writer.written, b"ABCDEFGHIJ");
+}
+
+#[test]
+fn test_write_to_wrapped() {
+    for &vectored in &[false, true] {
+        let mut ring = SliceRingImpl::<u8>::with_capacity(7);
+        ring.push_many_back(&[0; 5]);
+        ring.drop_many_front(5);
+        ring.push_many_back(b"ABCDE");
+        assert!(!ring.is_continuous());
+
+        let mut writer = ShortWriter::new(100, vectored);
+        let written = ring.write_to(&mut writer, 100).unwrap();
+        if vectored {
+            assert_eq!(written, 5);
+        } else {
+            // only wrote the part before the end of the buffer
+            assert_eq!(written, 3);
+            assert_eq!(ring.write_to(&mut writer, 100).unwrap(), 2);
+        }
+        assert!(ring.is_empty());
+        assert_eq!(writer.written, b"ABCDE");
+    }
+}
+
+#[test]
+fn test_write_to_writer_fails() {
+    struct Failing;
+
+    impl Write for Failing {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut ring = SliceRingImpl::<u8>::new();
+    ring.push_many_back(b"AB");
+    let err = ring.write_to(&mut Failing, 2).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(ring.len(), 2);
+
+    // a writer that accepts nothing
+    assert_eq!(ring.write_to(&mut &mut [][..], 2).unwrap(), 0);
+    assert_eq!(ring.len(), 2);
+}
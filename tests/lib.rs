@@ -14,8 +14,8 @@ macro_rules! test_string_windowing {
         let mut input = Cursor::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
         let mut output = Cursor::new(Vec::<u8>::new());
         let mut ring = $new;
-        let mut input_buffer: &mut [u8] = &mut [0; 4];
-        let mut window_buffer: &mut [u8] = &mut [0; WINDOW_SIZE];
+        let input_buffer: &mut [u8] = &mut [0; 4];
+        let window_buffer: &mut [u8] = &mut [0; WINDOW_SIZE];
 
         loop {
             let input_count = input.read(input_buffer).unwrap();
@@ -25,7 +25,7 @@ macro_rules! test_string_windowing {
             // read as long as enough samples are present (remain) in ring
             while WINDOW_SIZE <= ring.len() {
                 ring.read_many_front(window_buffer);
-                output.write(window_buffer).unwrap();
+                output.write_all(window_buffer).unwrap();
                 // step
                 ring.drop_many_front(STEP_SIZE);
             }
@@ -0,0 +1,80 @@
+//! model tests of the lock-free `Producer` and `Consumer`.
+//! run with `RUSTFLAGS="--cfg loom" cargo test --test loom --release`
+#![cfg(loom)]
+
+extern crate loom;
+extern crate strider;
+
+use loom::thread;
+use strider::{SliceRing, SliceRingImpl};
+
+#[test]
+fn loom_push_read_drop() {
+    loom::model(|| {
+        let (mut producer, mut consumer) = SliceRingImpl::<usize>::with_capacity(3).split();
+
+        let handle = thread::spawn(move || {
+            let mut pushed = producer.push_many_back(&[0, 1]);
+            pushed += producer.push_many_back(&[2, 3][..]);
+            pushed
+        });
+
+        // whatever is visible must be a prefix of the pushed values
+        let mut read = Vec::new();
+        for _ in 0..2 {
+            let mut output = [usize::MAX; 3];
+            let count = consumer.read_many_front(&mut output);
+            read.extend_from_slice(&output[..count]);
+            assert_eq!(consumer.drop_many_front(count), count);
+        }
+        let pushed = handle.join().unwrap();
+
+        let mut output = [usize::MAX; 4];
+        let count = consumer.read_many_front(&mut output);
+        read.extend_from_slice(&output[..count]);
+        assert_eq!(read, (0..pushed).collect::<Vec<usize>>());
+    });
+}
+
+#[test]
+fn loom_wrap_around() {
+    loom::model(|| {
+        let mut ring = SliceRingImpl::<usize>::with_capacity(3);
+        // start near the end of the buffer so pushes wrap
+        ring.push_many_back(&[0, 0, 0]);
+        ring.drop_many_front(3);
+        let (mut producer, mut consumer) = ring.split();
+
+        let handle = thread::spawn(move || {
+            let mut pushed = 0;
+            for value in 1..4 {
+                pushed += producer.push_many_back(&[value]);
+            }
+            pushed
+        });
+
+        let (front, back) = consumer.read_slices_front(3);
+        let mut read = front.to_vec();
+        read.extend_from_slice(back);
+        let count = read.len();
+        consumer.drop_many_front(count);
+        let pushed = handle.join().unwrap();
+
+        let (front, back) = consumer.read_slices_front(3);
+        read.extend_from_slice(front);
+        read.extend_from_slice(back);
+        assert_eq!(read, (1..pushed + 1).collect::<Vec<usize>>());
+    });
+}
+
+#[test]
+fn loom_drop_halves_on_different_threads() {
+    loom::model(|| {
+        let (mut producer, consumer) = SliceRingImpl::<Box<usize>>::with_capacity(3).split();
+        let handle = thread::spawn(move || {
+            producer.push_many_back(&[Box::new(1), Box::new(2)]);
+        });
+        drop(consumer);
+        handle.join().unwrap();
+    });
+}
@@ -0,0 +1,66 @@
+#![cfg(all(feature = "mirrored", target_os = "linux"))]
+
+#[macro_use]
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::MirroredSliceRing;
+
+#[test]
+fn test_slice_ring_mirrored() {
+    test_slice_ring!(MirroredSliceRing::<i32>::new().unwrap());
+}
+
+#[test]
+fn test_capacity_is_rounded_to_pages() {
+    let ring = MirroredSliceRing::<u8>::with_capacity(1).unwrap();
+    assert!(ring.capacity() >= 1);
+    assert_eq!(ring.capacity() % 4096, 0);
+    // 12 bytes doesn't divide the page size
+    let ring = MirroredSliceRing::<[u32; 3]>::with_capacity(1000).unwrap();
+    assert!(ring.capacity() >= 1000);
+    assert_eq!((ring.capacity() * 12) % 4096, 0);
+}
+
+#[test]
+fn test_windows_are_contiguous_across_the_wrap_point() {
+    let mut ring = MirroredSliceRing::<[u32; 3]>::with_capacity(10).unwrap();
+    let capacity = ring.capacity();
+    ring.push_many_back(&(0..50).map(|i| [i, i + 1, i + 2]).collect::<Vec<[u32; 3]>>()[..]);
+    let mut next = 50;
+    for _ in 0..5 * capacity / 100 {
+        let input = (next..next + 100).map(|i| [i, i + 1, i + 2]).collect::<Vec<[u32; 3]>>();
+        next += 100;
+        ring.push_many_back(&input[..]);
+        let window = ring.read_front(150);
+        let first = window[0][0];
+        assert_eq!(window, &(first..first + window.len() as u32)
+            .map(|i| [i, i + 1, i + 2]).collect::<Vec<[u32; 3]>>()[..]);
+        let (first, second) = ring.read_slices_front(150);
+        assert_eq!(first, window);
+        assert!(second.is_empty());
+        ring.drop_many_front(100);
+    }
+    assert_eq!(ring.capacity(), capacity);
+}
+
+#[test]
+fn test_growth_keeps_elements() {
+    let mut ring = MirroredSliceRing::<u64>::new().unwrap();
+    let capacity = ring.capacity();
+    ring.push_many_back(&(0..capacity as u64).collect::<Vec<u64>>()[..]);
+    ring.drop_many_front(10);
+    ring.push_many_back(&(capacity as u64..capacity as u64 + 100).collect::<Vec<u64>>()[..]);
+    assert!(ring.capacity() > capacity);
+    assert_eq!(ring.as_slice(), &(10..capacity as u64 + 100).collect::<Vec<u64>>()[..]);
+}
+
+#[test]
+fn test_as_mut_slice() {
+    let mut ring = MirroredSliceRing::<i16>::new().unwrap();
+    ring.push_many_back(&[1, 2, 3]);
+    ring.as_mut_slice()[1] = 20;
+    assert_eq!(ring.read_front(10), &[1, 20, 3]);
+    ring.clear();
+    assert!(ring.is_empty());
+}
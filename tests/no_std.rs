@@ -0,0 +1,21 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// builds the library without the default `std` feature.
+/// `#![no_std]` is active then so any use of `std` fails the build.
+#[test]
+fn test_builds_without_std() {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let output = Command::new(cargo)
+        .args(["check", "--lib", "--no-default-features", "--offline", "--quiet"])
+        .arg("--manifest-path")
+        .arg(Path::new(manifest_dir).join("Cargo.toml"))
+        // a separate target dir so this doesn't wait for the lock
+        // held by the cargo process running the tests
+        .env("CARGO_TARGET_DIR", Path::new(manifest_dir).join("target").join("no_std"))
+        .output()
+        .expect("failed to run cargo");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
@@ -0,0 +1,88 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::{ArraySliceRing, BoundedSliceRing, Overflow, SliceRing, SliceRingExt,
+              SliceRingImpl};
+
+macro_rules! test_read_many_at {
+    ($new:expr) => {{
+        let mut ring = $new;
+        // wrap around the end of the buffer
+        ring.push_many_back(&[0; 12]);
+        ring.drop_many_front(12);
+        ring.push_many_back(&(0..10).collect::<Vec<i32>>()[..]);
+
+        let mut output = [-1; 4];
+        assert_eq!(ring.read_many_at(0, &mut output), 4);
+        assert_eq!(output, [0, 1, 2, 3]);
+        assert_eq!(ring.read_many_at(3, &mut output), 4);
+        assert_eq!(output, [3, 4, 5, 6]);
+
+        // less elements after the offset than fit into the output
+        let mut output = [-1; 4];
+        assert_eq!(ring.read_many_at(8, &mut output), 2);
+        assert_eq!(output, [8, 9, -1, -1]);
+        assert_eq!(ring.read_many_at(10, &mut output), 0);
+        assert_eq!(ring.read_many_at(1000, &mut output), 0);
+        assert_eq!(ring.read_many_at(usize::MAX, &mut output), 0);
+        assert_eq!(output, [8, 9, -1, -1]);
+
+        // the ring is unchanged
+        let mut all = [0; 10];
+        assert_eq!(ring.read_many_front(&mut all), 10);
+        assert_eq!(all.to_vec(), (0..10).collect::<Vec<i32>>());
+    }}
+}
+
+#[test]
+fn test_read_many_at_deque() {
+    test_read_many_at!(VecDeque::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_read_many_at_optimized() {
+    test_read_many_at!(SliceRingImpl::<i32>::with_capacity(15));
+}
+
+#[test]
+fn test_read_many_at_bounded() {
+    test_read_many_at!(BoundedSliceRing::<i32>::new(15, Overflow::Panic));
+}
+
+#[test]
+fn test_read_many_at_array() {
+    test_read_many_at!(ArraySliceRing::<i32, 16>::new());
+}
+
+#[test]
+#[cfg(all(feature = "mirrored", target_os = "linux"))]
+fn test_read_many_at_mirrored() {
+    test_read_many_at!(strider::MirroredSliceRing::<i32>::new().unwrap());
+}
+
+#[test]
+fn test_get_and_index() {
+    let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+    ring.push_many_back(&[0; 6]);
+    ring.drop_many_front(6);
+    ring.push_many_back(&[1, 2, 3, 4]);
+    assert!(!ring.is_continuous());
+
+    assert_eq!(ring.get(0), Some(&1));
+    assert_eq!(ring.get(3), Some(&4));
+    assert_eq!(ring.get(4), None);
+    assert_eq!(ring[2], 3);
+
+    *ring.get_mut(1).unwrap() = 20;
+    ring[3] = 40;
+    assert_eq!(ring.get_mut(4), None);
+    assert_eq!(ring.read_slices_front(4), (&[1, 20][..], &[3, 40][..]));
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds: the len is 2 but the index is 2")]
+fn test_index_out_of_bounds() {
+    let mut ring = SliceRingImpl::<i32>::new();
+    ring.push_many_back(&[1, 2]);
+    let _ = ring[2];
+}
@@ -0,0 +1,88 @@
+extern crate strider;
+use strider::{OverlapAddRing, SliceRingExt, SliceRingImpl, Windower};
+#[cfg(feature = "std")]
+use strider::window::Window;
+
+mod common;
+use common::Rng;
+
+#[test]
+fn test_against_naive_overlap_add() {
+    let mut rng = Rng(0x2545F4914F6CDD1D);
+    for &step_size in &[1, 3, 8, 20] {
+        let mut ring = OverlapAddRing::new(step_size);
+        let mut expected = Vec::new();
+        let mut output = Vec::new();
+        for i in 0..200 {
+            let frame = (0..rng.below(32)).map(|j| (i * 100 + j) as i64).collect::<Vec<i64>>();
+            let start = i * step_size;
+            if expected.len() < start + frame.len() {
+                expected.resize(start + frame.len(), 0);
+            }
+            for (j, value) in frame.iter().enumerate() {
+                expected[start + j] += *value;
+            }
+            ring.add_frame(&frame[..]);
+            assert_eq!(ring.ready(), start + step_size - output.len());
+
+            let mut buffer = vec![0; rng.below(40)];
+            let count = ring.pop_many_front(&mut buffer[..]);
+            output.extend_from_slice(&buffer[..count]);
+        }
+        ring.finish();
+        let mut rest = vec![0; ring.ready()];
+        assert_eq!(ring.pop_many_front(&mut rest[..]), rest.len());
+        output.extend_from_slice(&rest[..]);
+        assert!(ring.is_empty());
+
+        // the values between the last frame and the last step are zero
+        expected.resize(output.len(), 0);
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_only_finished_values_leave() {
+    let mut ring = OverlapAddRing::new(2);
+    ring.add_frame(&[1.0, 1.0, 1.0]);
+    assert_eq!(ring.len(), 3);
+    assert_eq!(ring.ready(), 2);
+    assert_eq!(ring.read_slices_ready(), (&[1.0, 1.0][..], &[][..]));
+    assert_eq!(ring.drop_many_front(5), 2);
+    assert_eq!(ring.ready(), 0);
+    ring.add_frame(&[2.0]);
+    let mut output = [0.0; 4];
+    assert_eq!(ring.pop_many_front(&mut output), 2);
+    assert_eq!(output[..2], [3.0, 0.0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_analysis_resynthesis_roundtrip() {
+    // a periodic hann window with 50% overlap sums to one
+    const WINDOW_SIZE: usize = 64;
+    const STEP_SIZE: usize = 32;
+    let window = Window::Hann.periodic::<f64>(WINDOW_SIZE);
+    let input = (0..1000).map(|i| (i as f64 * 0.1).sin()).collect::<Vec<f64>>();
+
+    let mut analysis = Windower::new(SliceRingImpl::new(), WINDOW_SIZE, STEP_SIZE);
+    let mut resynthesis = OverlapAddRing::new(STEP_SIZE);
+    let mut frame = vec![0.0; WINDOW_SIZE];
+    let mut output = Vec::new();
+    let mut chunk = vec![0.0; STEP_SIZE];
+    for values in input.chunks(100) {
+        analysis.push(values);
+        while analysis.ready() > 0 {
+            analysis.ring().read_many_front_windowed(&mut frame[..], &window[..]);
+            // step over the window that was just read
+            analysis.next_window();
+            resynthesis.add_frame(&frame[..]);
+            let count = resynthesis.pop_many_front(&mut chunk[..]);
+            output.extend_from_slice(&chunk[..count]);
+        }
+    }
+    // the first half window only got one frame
+    for i in STEP_SIZE..output.len() {
+        assert!((output[i] - input[i]).abs() < 1e-9, "{} {} {}", i, output[i], input[i]);
+    }
+}
@@ -0,0 +1,130 @@
+extern crate strider;
+use strider::{ArraySliceRing, BoundedSliceRing, OverlapAddRing, Overflow, SliceRing,
+              SliceRingImpl};
+
+mod common;
+use common::{ids, Counters, DropCounter};
+
+macro_rules! test_pop_many_front {
+    ($new:expr) => {{
+        let counters = Counters::new();
+        let mut ring = $new;
+        // wrap around the end of the buffer
+        ring.push_many_back(&counters.make(0..12));
+        ring.drop_many_front(12);
+        ring.push_many_back(&counters.make(0..6));
+        let mut output = counters.make(100..104);
+        counters.reset();
+
+        assert_eq!(ring.pop_many_front(&mut output), 4);
+        assert_eq!(ids(&output), [0, 1, 2, 3]);
+        // moved, not cloned. the values `output` held before were dropped
+        assert_eq!(counters.clones(), 0);
+        assert_eq!(counters.drops(), 4);
+        assert_eq!(ring.len(), 2);
+
+        // less elements than fit into the output
+        let mut output = counters.make(100..104);
+        counters.reset();
+        assert_eq!(ring.pop_many_front(&mut output), 2);
+        assert_eq!(ids(&output), [4, 5, 102, 103]);
+        assert_eq!(counters.drops(), 2);
+        assert!(ring.is_empty());
+        assert_eq!(ring.pop_many_front(&mut output), 0);
+        assert_eq!(ids(&output), [4, 5, 102, 103]);
+
+        // the ring is still usable
+        ring.push_many_back(&counters.make(6..8));
+        let mut output = counters.make(0..1);
+        assert_eq!(ring.pop_many_front(&mut output), 1);
+        assert_eq!(ids(&output), [6]);
+
+        counters.reset();
+        drop(ring);
+        assert_eq!(counters.drops(), 1);
+        assert_eq!(counters.clones(), 0);
+    }}
+}
+
+#[test]
+fn test_pop_many_front_optimized() {
+    test_pop_many_front!(SliceRingImpl::<DropCounter>::with_capacity(15));
+}
+
+#[test]
+fn test_pop_many_front_bounded() {
+    test_pop_many_front!(BoundedSliceRing::<DropCounter>::new(15, Overflow::Panic));
+}
+
+#[test]
+fn test_pop_many_front_array() {
+    test_pop_many_front!(ArraySliceRing::<DropCounter, 16>::new());
+}
+
+#[test]
+fn test_drain_front() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::<DropCounter>::with_capacity(7);
+    ring.push_many_back(&counters.make(0..6));
+    ring.drop_many_front(6);
+    ring.push_many_back(&counters.make(0..6));
+    assert!(!ring.is_continuous());
+    counters.reset();
+
+    let drained: Vec<DropCounter> = ring.drain_front(4).collect();
+    assert_eq!(ids(&drained), [0, 1, 2, 3]);
+    assert_eq!(counters.clones(), 0);
+    assert_eq!(counters.drops(), 0);
+    assert_eq!(ring.len(), 2);
+
+    let mut drain = ring.drain_front(100);
+    assert_eq!(drain.len(), 2);
+    assert_eq!(drain.next().map(|counter| counter.id), Some(4));
+    assert_eq!(drain.size_hint(), (1, Some(1)));
+    drop(drain);
+    assert!(ring.is_empty());
+    assert_eq!(ring.drain_front(1).next().map(|counter| counter.id), None);
+}
+
+#[test]
+fn test_drain_front_drops_the_rest() {
+    let counters = Counters::new();
+    let mut ring = SliceRingImpl::<DropCounter>::new();
+    ring.push_many_back(&counters.make(0..5));
+    counters.reset();
+
+    // only the yielded element and the rest of the drained range
+    // are gone afterwards
+    let first = ring.drain_front(3).next().unwrap();
+    assert_eq!(first.id, 0);
+    assert_eq!(counters.drops(), 2);
+    assert_eq!(ring.len(), 2);
+    assert_eq!(ring[0].id, 3);
+    drop(first);
+
+    // a leaked iterator leaves the elements it didn't yield in the ring
+    let mut drain = ring.drain_front(2);
+    drain.next();
+    std::mem::forget(drain);
+    assert_eq!(ring.len(), 1);
+    assert_eq!(ring[0].id, 4);
+}
+
+#[test]
+fn test_drain_front_bounded() {
+    let mut ring = BoundedSliceRing::<i32>::new(4, Overflow::Overwrite);
+    ring.push_many_back(&[1, 2, 3, 4, 5, 6]);
+    assert_eq!(ring.drain_front(2).collect::<Vec<i32>>(), [3, 4]);
+    assert_eq!(ring.len(), 2);
+}
+
+#[test]
+fn test_overlap_add_pop_many_front_moves() {
+    let mut ring = OverlapAddRing::<f32>::new(2);
+    ring.add_frame(&[1., 1., 1., 1.]);
+    let mut output = [0.; 4];
+    assert_eq!(ring.pop_many_front(&mut output), 2);
+    assert_eq!(output[..2], [1., 1.]);
+    assert_eq!(ring.ready(), 0);
+    assert_eq!(ring.len(), 2);
+}
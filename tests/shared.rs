@@ -0,0 +1,207 @@
+#![cfg(feature = "std")]
+
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+extern crate strider;
+use strider::SharedSliceRing;
+
+#[test]
+fn test_push_read_drop() {
+    let ring = SharedSliceRing::new(4);
+    assert_eq!(ring.capacity(), 4);
+    assert_eq!(ring.push_many_back(&[0, 1, 2, 3, 4, 5]), 4);
+    assert_eq!(ring.free(), 0);
+    let mut output = [0; 3];
+    assert_eq!(ring.read_many_front(&mut output), 3);
+    assert_eq!(output, [0, 1, 2]);
+    assert_eq!(ring.drop_many_front(3), 3);
+    assert_eq!(ring.len(), 1);
+    ring.clear();
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn test_wait_for_len_wakes_on_push() {
+    let ring = Arc::new(SharedSliceRing::new(16));
+    let producer = ring.clone();
+    let handle = thread::spawn(move || {
+        for value in 0..8 {
+            thread::sleep(Duration::from_millis(1));
+            producer.push_many_back(&[value]);
+        }
+    });
+    assert!(ring.wait_for_len(8) >= 8);
+    let mut output = [0; 8];
+    ring.read_many_front(&mut output);
+    assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7]);
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_push_blocking_waits_for_space() {
+    let ring = Arc::new(SharedSliceRing::new(5));
+    let producer = ring.clone();
+    let handle = thread::spawn(move || {
+        producer.push_many_back_blocking(&(0..100).collect::<Vec<u32>>()[..])
+    });
+
+    let mut read = Vec::new();
+    let mut output = [0; 3];
+    while read.len() < 100 {
+        let len = ring.wait_for_len(1);
+        let count = ring.read_many_front(&mut output[..len.min(3)]);
+        read.extend_from_slice(&output[..count]);
+        ring.drop_many_front(count);
+    }
+    assert_eq!(handle.join().unwrap(), 100);
+    assert_eq!(read, (0..100).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_close_releases_waiters() {
+    let ring = Arc::new(SharedSliceRing::new(4));
+    ring.push_many_back(&[1, 2]);
+
+    let consumer = ring.clone();
+    let reader = thread::spawn(move || consumer.wait_for_len(4));
+    let producer = ring.clone();
+    let writer = thread::spawn(move || producer.push_many_back_blocking(&[3, 4, 5, 6, 7]));
+
+    thread::sleep(Duration::from_millis(10));
+    ring.close();
+    assert!(ring.is_closed());
+    assert!(reader.join().unwrap() <= 4);
+    // the values that fit were pushed before closing
+    assert_eq!(writer.join().unwrap(), 2);
+    assert_eq!(ring.len(), 4);
+    // waiting returns right away once closed
+    assert_eq!(ring.wait_for_free(4), 0);
+}
+
+#[test]
+#[should_panic(expected = "waiting for 5 elements in a ring of capacity 4")]
+fn test_wait_for_more_than_capacity() {
+    SharedSliceRing::<u8>::new(4).wait_for_len(5);
+}
+
+#[cfg(feature = "async")]
+mod async_tests {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    use strider::SharedSliceRing;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// runs `future` to completion on the current thread
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    /// polls `future` once with a waker that does nothing
+    fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        let waker = Waker::noop();
+        Pin::new(future).poll(&mut Context::from_waker(waker))
+    }
+
+    #[test]
+    fn test_wait_for_len_async() {
+        let ring = Arc::new(SharedSliceRing::new(64));
+        let producer = ring.clone();
+        let handle = thread::spawn(move || {
+            for chunk in (0..48).collect::<Vec<u32>>().chunks(3) {
+                producer.push_many_back_blocking(chunk);
+            }
+            producer.close();
+        });
+
+        let mut windows = Vec::new();
+        let mut window = [0; 16];
+        while block_on(ring.wait_for_len_async(16)) >= 16 {
+            ring.read_many_front(&mut window);
+            windows.push(window[0]);
+            ring.drop_many_front(8);
+        }
+        handle.join().unwrap();
+        assert_eq!(windows, vec![0, 8, 16, 24, 32]);
+    }
+
+    #[test]
+    fn test_wait_for_free_async() {
+        let ring = SharedSliceRing::new(4);
+        ring.push_many_back(&[0, 1, 2, 3]);
+
+        let mut future = ring.wait_for_free_async(2);
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+        ring.drop_many_front(1);
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+        ring.drop_many_front(1);
+        assert_eq!(poll_once(&mut future), Poll::Ready(2));
+    }
+
+    #[test]
+    fn test_close_resolves_futures() {
+        let ring = SharedSliceRing::<u8>::new(4);
+        let mut future = ring.wait_for_len_async(1);
+        assert_eq!(poll_once(&mut future), Poll::Pending);
+        ring.close();
+        assert_eq!(poll_once(&mut future), Poll::Ready(0));
+    }
+
+    /// locks the ring when woken like an executor
+    /// that polls the woken task right away
+    struct LockingWaker {
+        ring: Arc<SharedSliceRing<u8>>,
+        woken: AtomicUsize,
+    }
+
+    impl Wake for LockingWaker {
+        fn wake(self: Arc<Self>) {
+            self.ring.len();
+            self.woken.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_wakes_after_unlocking() {
+        let ring = Arc::new(SharedSliceRing::<u8>::new(4));
+        let waker = Arc::new(LockingWaker { ring: ring.clone(), woken: AtomicUsize::new(0) });
+        let task_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&task_waker);
+
+        let mut future = ring.wait_for_len_async(1);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+        ring.push_many_back(&[0, 1, 2, 3]);
+        assert_eq!(waker.woken.load(Ordering::SeqCst), 1);
+
+        let mut future = ring.wait_for_free_async(1);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+        ring.drop_many_front(1);
+        assert_eq!(waker.woken.load(Ordering::SeqCst), 2);
+
+        let mut future = ring.wait_for_free_async(2);
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+        ring.close();
+        assert_eq!(waker.woken.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::{ArraySliceRing, BoundedSliceRing, Overflow, SliceRingImpl};
+
+macro_rules! test_read_slices_front {
+    ($new:expr) => {{
+        let mut ring = $new;
+        assert_eq!(ring.read_slices_front(10), (&[][..], &[][..]));
+
+        ring.push_many_back(&[0, 1, 2, 3, 4, 5]);
+        let (first, second) = ring.read_slices_front(4);
+        assert_eq!([first, second].concat(), vec![0, 1, 2, 3]);
+
+        // wrap around the end of the underlying buffer
+        for i in 0..10 {
+            ring.drop_many_front(4);
+            ring.push_many_back(&[6 + 4 * i, 7 + 4 * i, 8 + 4 * i, 9 + 4 * i]);
+            let (first, second) = ring.read_slices_front(5);
+            let start = 4 + 4 * i;
+            assert_eq!([first, second].concat(),
+                       (start..start + 5).collect::<Vec<i32>>());
+            // the slices cover exactly what `read_many_front` reads
+            let mut output = [0; 6];
+            assert_eq!(ring.read_many_front(&mut output[..]), 6);
+            let (first, second) = ring.read_slices_front(6);
+            assert_eq!([first, second].concat(), output.to_vec());
+        }
+
+        // read more than present
+        let (first, second) = ring.read_slices_front(100);
+        assert_eq!(first.len() + second.len(), 6);
+        // read nothing
+        assert_eq!(ring.read_slices_front(0), (&[][..], &[][..]));
+    }}
+}
+#[test]
+fn test_read_slices_front_deque() {
+    test_read_slices_front!(VecDeque::<i32>::with_capacity(7));
+}
+#[test]
+fn test_read_slices_front_optimized() {
+    test_read_slices_front!(SliceRingImpl::<i32>::with_capacity(7));
+}
+#[test]
+fn test_read_slices_front_bounded() {
+    test_read_slices_front!(BoundedSliceRing::<i32>::new(7, Overflow::Panic));
+}
+#[test]
+fn test_read_slices_front_array() {
+    test_read_slices_front!(ArraySliceRing::<i32, 8>::new());
+}
+
+#[test]
+fn test_read_slices_front_straddles_wrap_point() {
+    let mut ring = SliceRingImpl::<i32>::with_capacity(7);
+    ring.push_many_back(&[0, 1, 2, 3, 4, 5]);
+    ring.drop_many_front(5);
+    ring.push_many_back(&[6, 7, 8, 9]);
+    assert_eq!(ring.read_slices_front(5), (&[5, 6, 7][..], &[8, 9][..]));
+    // a window before the wrap point is a single slice
+    assert_eq!(ring.read_slices_front(3), (&[5, 6, 7][..], &[][..]));
+}
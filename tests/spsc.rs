@@ -0,0 +1,133 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+
+extern crate strider;
+use strider::{SliceRing, SliceRingImpl};
+
+mod common;
+use common::Counters;
+
+#[test]
+fn test_split_keeps_elements() {
+    let mut ring = SliceRingImpl::<u32>::with_capacity(7);
+    ring.push_many_back(&[0, 1, 2, 3, 4, 5]);
+    ring.drop_many_front(4);
+    ring.push_many_back(&[6, 7, 8]);
+    let (mut producer, mut consumer) = ring.split();
+    assert_eq!(producer.capacity(), 7);
+    assert_eq!(consumer.len(), 5);
+    assert_eq!(producer.free(), 2);
+
+    // only as many values as fit are pushed
+    assert_eq!(producer.push_many_back(&[9, 10, 11]), 2);
+    assert_eq!(producer.free(), 0);
+    assert_eq!(consumer.read_slices_front(7), (&[4, 5, 6, 7][..], &[8, 9, 10][..]));
+
+    let mut output = [0; 3];
+    assert_eq!(consumer.read_many_front(&mut output), 3);
+    assert_eq!(output, [4, 5, 6]);
+    assert_eq!(consumer.drop_many_front(3), 3);
+    assert_eq!(producer.free(), 3);
+    assert_eq!(producer.push_many_back(&[11, 12, 13]), 3);
+
+    let mut output = [0; 8];
+    assert_eq!(consumer.read_many_front(&mut output), 7);
+    assert_eq!(output[..7], [7, 8, 9, 10, 11, 12, 13]);
+    assert_eq!(consumer.drop_many_front(10), 7);
+    assert!(consumer.is_empty());
+}
+
+#[test]
+fn test_split_threads() {
+    const TOTAL: u64 = 100_000;
+    let (mut producer, mut consumer) = SliceRingImpl::<u64>::with_capacity(255).split();
+
+    let handle = thread::spawn(move || {
+        let values = (0..TOTAL).collect::<Vec<u64>>();
+        let mut pushed = 0;
+        let mut step = 1;
+        while pushed < values.len() {
+            let end = (pushed + step).min(values.len());
+            pushed += producer.push_many_back(&values[pushed..end]);
+            step = step % 97 + 1;
+        }
+    });
+
+    let mut window = vec![0; 64];
+    let mut expected = 0;
+    let mut step = 1;
+    while expected < TOTAL {
+        let count = consumer.read_many_front(&mut window[..step]);
+        for value in &window[..count] {
+            assert_eq!(*value, expected);
+            expected += 1;
+        }
+        assert_eq!(consumer.drop_many_front(count), count);
+        step = step % 64 + 1;
+    }
+    handle.join().unwrap();
+    assert!(consumer.is_empty());
+}
+
+#[test]
+fn test_split_drops_each_element_once() {
+    let counters = Counters::new();
+    let values = counters.make(0..5);
+    let mut ring = SliceRingImpl::with_capacity(7);
+    ring.push_many_back(&values[..2]);
+    let (mut producer, mut consumer) = ring.split();
+    assert_eq!(producer.push_many_back(&values[2..]), 3);
+    assert_eq!(counters.drops(), 0);
+    assert_eq!(consumer.drop_many_front(2), 2);
+    assert_eq!(counters.drops(), 2);
+
+    // the remaining elements are dropped with the last half
+    drop(consumer);
+    assert_eq!(counters.drops(), 2);
+    drop(producer);
+    assert_eq!(counters.drops(), 5);
+    drop(values);
+    assert_eq!(counters.drops(), 10);
+}
+
+#[test]
+fn test_split_zero_sized() {
+    let (mut producer, mut consumer) = SliceRingImpl::<()>::new().split();
+    assert_eq!(producer.push_many_back(&[(); 100]), 100);
+    assert_eq!(consumer.len(), 100);
+    assert_eq!(consumer.drop_many_front(60), 60);
+    assert_eq!(consumer.len(), 40);
+}
+
+/// clones fine until the shared counter reaches zero
+struct PanickingClone(Rc<Cell<usize>>);
+
+impl Clone for PanickingClone {
+    fn clone(&self) -> PanickingClone {
+        let left = self.0.get();
+        if left == 0 {
+            panic!("clone failed");
+        }
+        self.0.set(left - 1);
+        PanickingClone(self.0.clone())
+    }
+}
+
+#[test]
+fn test_split_clone_panic_publishes_written() {
+    let clones = Rc::new(Cell::new(2));
+    let values = vec![PanickingClone(clones.clone()), PanickingClone(clones.clone()),
+                      PanickingClone(clones.clone())];
+    let (mut producer, consumer) = SliceRingImpl::with_capacity(7).split();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        producer.push_many_back(&values[..]);
+    }));
+    assert!(result.is_err());
+    // the two values cloned before the panic were handed to the consumer
+    assert_eq!(consumer.len(), 2);
+    drop(consumer);
+    drop(producer);
+    drop(values);
+    assert_eq!(Rc::strong_count(&clones), 1);
+}
@@ -0,0 +1,100 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+extern crate strider;
+use strider::{SliceRing, SliceRingExt, SliceRingImpl};
+
+/// a ring holding `values` whose elements wrap around
+/// the end of the underlying buffer
+fn wrapped(values: &[i32]) -> SliceRingImpl<i32> {
+    let mut ring = SliceRingImpl::with_capacity(7);
+    ring.push_many_back(&[0; 5]);
+    ring.drop_many_front(5);
+    ring.push_many_back(values);
+    ring
+}
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_eq_ignores_layout() {
+    let wrapped = wrapped(&[1, 2, 3, 4, 5]);
+    assert!(!wrapped.is_continuous());
+    let mut continuous = SliceRingImpl::with_capacity(100);
+    continuous.push_many_back(&[1, 2, 3, 4, 5]);
+    assert!(continuous.is_continuous());
+
+    assert_eq!(wrapped, continuous);
+    assert_eq!(hash(&wrapped), hash(&continuous));
+
+    continuous.drop_many_back(1);
+    assert_ne!(wrapped, continuous);
+    continuous.push_many_back(&[6]);
+    assert_ne!(wrapped, continuous);
+
+    assert_eq!(SliceRingImpl::<i32>::new(), SliceRingImpl::with_capacity(100));
+}
+
+#[test]
+fn test_hash_like_vec_deque() {
+    let ring = wrapped(&[1, 2, 3, 4, 5]);
+    let deque: VecDeque<i32> = (1..6).collect();
+    assert_eq!(hash(&ring), hash(&deque));
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(format!("{:?}", wrapped(&[1, 2, 3, 4, 5])), "[1, 2, 3, 4, 5]");
+    assert_eq!(format!("{:?}", SliceRingImpl::<i32>::new()), "[]");
+}
+
+#[test]
+fn test_clone() {
+    let ring = wrapped(&[1, 2, 3, 4, 5]);
+    let clone = ring.clone();
+    assert_eq!(clone, ring);
+    assert_eq!(clone.read_slices_front(5), (&[1, 2, 3, 4, 5][..], &[][..]));
+
+    let mut target = SliceRingImpl::with_capacity(100);
+    target.push_many_back(&[9; 50]);
+    let cap = target.cap();
+    target.clone_from(&ring);
+    assert_eq!(target, ring);
+    assert_eq!(target.cap(), cap);
+}
+
+#[test]
+fn test_extend_and_collect() {
+    // doesn't need `Clone`
+    struct Token(i32);
+
+    let mut ring: SliceRingImpl<Token> = (0..3).map(Token).collect();
+    ring.extend((3..20).map(Token));
+    assert_eq!(ring.len(), 20);
+    let ids: Vec<i32> = ring.drain_front(20).map(|token| token.0).collect();
+    assert_eq!(ids, (0..20).collect::<Vec<i32>>());
+
+    let mut ring = wrapped(&[1, 2]);
+    ring.extend(&[3, 4, 5]);
+    ring.extend(vec![6, 7]);
+    assert_eq!(ring, (1..8).collect::<SliceRingImpl<i32>>());
+}
+
+#[test]
+fn test_derive() {
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+    struct State {
+        history: SliceRingImpl<i32>,
+    }
+
+    let mut state = State::default();
+    state.history.push_many_back(&[1, 2]);
+    let copy = state.clone();
+    assert_eq!(copy, state);
+    assert_eq!(format!("{:?}", copy), "State { history: [1, 2] }");
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "std")]
+
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::{ArraySliceRing, BoundedSliceRing, Overflow, SliceRing, SliceRingExt, SliceRingImpl};
+use strider::window::Window;
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn test_known_values() {
+    assert_close(&Window::Rectangular.periodic(3), &[1.0, 1.0, 1.0]);
+    assert_close(&Window::Hann.symmetric(5), &[0.0, 0.5, 1.0, 0.5, 0.0]);
+    assert_close(&Window::Hann.periodic(4), &[0.0, 0.5, 1.0, 0.5]);
+    assert_close(&Window::Hamming.symmetric(3), &[0.08, 1.0, 0.08]);
+    assert_close(&Window::BlackmanHarris.symmetric(3), &[0.00006, 1.0, 0.00006]);
+    // beta 0 is rectangular
+    assert_close(&Window::Kaiser(0.0).symmetric(4), &[1.0; 4]);
+    // i0(5) = 27.239871823604442
+    let edge = 1.0 / 27.239871823604442;
+    assert_close(&Window::Kaiser(5.0).symmetric(3), &[edge, 1.0, edge]);
+}
+
+#[test]
+fn test_degenerate_sizes() {
+    for window in &[Window::Rectangular, Window::Hann, Window::Hamming,
+                    Window::BlackmanHarris, Window::Kaiser(8.6)] {
+        assert!(window.periodic::<f64>(0).is_empty());
+        assert!(window.symmetric::<f64>(0).is_empty());
+        assert_close(&window.symmetric(1), &[1.0]);
+    }
+}
+
+#[test]
+fn test_symmetric_and_periodic() {
+    for window in &[Window::Hann, Window::Hamming, Window::BlackmanHarris, Window::Kaiser(8.6)] {
+        let symmetric = window.symmetric::<f64>(33);
+        let reversed = symmetric.iter().rev().cloned().collect::<Vec<f64>>();
+        assert_close(&symmetric, &reversed);
+        assert!((symmetric[16] - 1.0).abs() < 1e-12);
+        // a periodic window is a symmetric one of size + 1 without the last value
+        assert_close(&window.periodic(32), &symmetric[..32]);
+        assert!(symmetric.iter().all(|&c| (0.0..=1.0 + 1e-12).contains(&c)));
+    }
+}
+
+#[test]
+fn test_f32_tables() {
+    assert_eq!(Window::Hann.periodic::<f32>(4), vec![0.0, 0.5, 1.0, 0.5]);
+}
+
+macro_rules! test_read_many_front_windowed {
+    ($new:expr) => {{
+        let mut ring = $new;
+        let table = Window::Hann.periodic::<f32>(4);
+        // wrap around the end of the buffer
+        ring.push_many_back(&[9.0; 5]);
+        ring.drop_many_front(5);
+        ring.push_many_back(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        let mut output = [-1.0; 4];
+        assert_eq!(ring.read_many_front_windowed(&mut output, &table), 4);
+        assert_eq!(output, [0.0, 1.0, 3.0, 2.0]);
+        // the ring is unchanged
+        ring.drop_many_front(3);
+        assert_eq!(ring.read_many_front_windowed(&mut output, &table), 2);
+        assert_eq!(output, [0.0, 2.5, 3.0, 2.0]);
+    }}
+}
+
+#[test]
+fn test_read_many_front_windowed_deque() {
+    test_read_many_front_windowed!(VecDeque::<f32>::with_capacity(7));
+}
+
+#[test]
+fn test_read_many_front_windowed_optimized() {
+    test_read_many_front_windowed!(SliceRingImpl::<f32>::with_capacity(7));
+}
+
+#[test]
+fn test_read_many_front_windowed_bounded() {
+    test_read_many_front_windowed!(BoundedSliceRing::<f32>::new(7, Overflow::Panic));
+}
+
+#[test]
+fn test_read_many_front_windowed_array() {
+    test_read_many_front_windowed!(ArraySliceRing::<f32, 8>::new());
+}
+
+#[test]
+#[should_panic(expected = "output length must equal the window length")]
+fn test_read_many_front_windowed_wrong_length() {
+    let ring = SliceRingImpl::<f32>::new();
+    ring.read_many_front_windowed(&mut [0.0; 4], &[1.0; 3]);
+}
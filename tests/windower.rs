@@ -0,0 +1,188 @@
+use std::collections::VecDeque;
+
+extern crate strider;
+use strider::{ArraySliceRing, SliceRingImpl, Tail, Windower};
+
+/// all windows of `input` computed the naive way
+fn expected_windows(input: &[u32], window_size: usize, step_size: usize) -> Vec<Vec<u32>> {
+    let mut windows = Vec::new();
+    let mut start = 0;
+    while start + window_size <= input.len() {
+        windows.push(input[start..start + window_size].to_vec());
+        start += step_size;
+    }
+    windows
+}
+
+macro_rules! test_windower {
+    ($new:expr) => {{
+        let input = (0..1000).collect::<Vec<u32>>();
+        for &(window_size, step_size) in &[(1, 1), (4, 2), (16, 16), (8, 3), (4, 10), (32, 7)] {
+            for &chunk_size in &[1, 5, 64] {
+                let mut windower = Windower::new($new, window_size, step_size);
+                let mut windows = Vec::new();
+                let mut window = vec![0; window_size];
+                for (i, chunk) in input.chunks(chunk_size).enumerate() {
+                    windower.push(chunk);
+                    let ready = windower.ready();
+                    for _ in 0..ready {
+                        // alternate between both ways of reading windows
+                        if i % 2 == 0 {
+                            assert_eq!(windower.next_window_into(&mut window[..]), window_size);
+                            windows.push(window.clone());
+                        } else {
+                            windows.push(windower.next_window().unwrap().to_vec());
+                        }
+                    }
+                    assert_eq!(windower.ready(), 0);
+                    assert!(windower.next_window().is_none());
+                    assert!(windower.missing() > 0);
+                }
+                assert_eq!(windows, expected_windows(&input[..], window_size, step_size));
+            }
+        }
+    }}
+}
+
+#[test]
+fn test_windower_deque() {
+    test_windower!(VecDeque::new());
+}
+
+#[test]
+fn test_windower_optimized() {
+    test_windower!(SliceRingImpl::new());
+}
+
+#[test]
+fn test_windower_array() {
+    test_windower!(ArraySliceRing::<u32, 128>::new());
+}
+
+#[test]
+fn test_step_larger_than_window_skips_later_values() {
+    let mut windower = Windower::new(SliceRingImpl::new(), 2, 5);
+    windower.push(&[0, 1, 2]);
+    assert_eq!(windower.next_window(), Some(&[0, 1][..]));
+    assert_eq!(windower.len(), 0);
+    // 3 and 4 fall between windows
+    assert_eq!(windower.missing(), 4);
+    windower.push(&[3, 4, 5]);
+    assert_eq!(windower.len(), 1);
+    assert_eq!(windower.missing(), 1);
+    windower.push(&[6]);
+    assert_eq!(windower.next_window(), Some(&[5, 6][..]));
+}
+
+#[test]
+fn test_windower_keeps_values_already_in_ring() {
+    let mut ring = VecDeque::new();
+    ring.extend([1, 2, 3]);
+    let mut windower = Windower::new(ring, 3, 1);
+    assert_eq!(windower.ready(), 1);
+    assert_eq!(windower.next_window(), Some(&[1, 2, 3][..]));
+    assert_eq!(windower.into_inner(), vec![2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "output length must equal the window size")]
+fn test_next_window_into_wrong_length() {
+    let mut windower = Windower::new(SliceRingImpl::<u8>::new(), 4, 2);
+    windower.next_window_into(&mut [0; 3]);
+}
+
+#[test]
+#[should_panic(expected = "step size must not be zero")]
+fn test_zero_step_size() {
+    Windower::<u8, _>::new(SliceRingImpl::new(), 4, 0);
+}
+
+/// all windows of a finished `input` computed the naive way
+/// including the last window for the values at the end
+fn expected_windows_with_tail(input: &[u32], window_size: usize, step_size: usize,
+                              tail: Tail<u32>) -> Vec<Vec<u32>> {
+    let mut windows = expected_windows(input, window_size, step_size);
+    let start = windows.len() * step_size;
+    let previous_end = if windows.is_empty() { 0 } else { start - step_size + window_size };
+    if start < input.len() && previous_end < input.len() {
+        let mut window = input[start..].to_vec();
+        match tail {
+            Tail::Drop => return windows,
+            Tail::ZeroPad => window.resize(window_size, 0),
+            Tail::Pad(value) => window.resize(window_size, value),
+            Tail::Short => {}
+        }
+        windows.push(window);
+    }
+    windows
+}
+
+#[test]
+fn test_tail_policies() {
+    for &tail in &[Tail::Drop, Tail::ZeroPad, Tail::Pad(7), Tail::Short] {
+        for &(window_size, step_size) in &[(4, 2), (4, 4), (8, 3), (3, 5), (5, 1)] {
+            for total in 0..20 {
+                let input = (100..100 + total).collect::<Vec<u32>>();
+                let mut windower = Windower::new(SliceRingImpl::new(), window_size, step_size);
+                windower.set_tail(tail);
+                let mut windows = Vec::new();
+                for chunk in input.chunks(3) {
+                    windower.push(chunk);
+                    while let Some(window) = windower.next_window() {
+                        windows.push(window.to_vec());
+                    }
+                }
+                windower.finish();
+                assert!(windower.is_finished());
+                let ready = windower.ready();
+                assert_eq!(ready == 0, windower.missing() > 0);
+                for _ in 0..ready {
+                    windows.push(windower.next_window().unwrap().to_vec());
+                }
+                assert_eq!(windower.next_window(), None);
+                assert_eq!(windows, expected_windows_with_tail(&input[..], window_size,
+                                                               step_size, tail),
+                           "{:?} window {} step {} total {}", tail, window_size, step_size, total);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tail_before_finish() {
+    let mut windower = Windower::new(SliceRingImpl::new(), 4, 2);
+    windower.set_tail(Tail::Short);
+    windower.push(&[1, 2, 3]);
+    // the stream might go on
+    assert_eq!(windower.ready(), 0);
+    assert_eq!(windower.missing(), 1);
+    windower.finish();
+    assert_eq!(windower.ready(), 1);
+    assert_eq!(windower.missing(), 0);
+    let mut window = [0; 4];
+    assert_eq!(windower.next_window_into(&mut window), 3);
+    assert_eq!(window, [1, 2, 3, 0]);
+}
+
+#[test]
+fn test_pad_front() {
+    // the docs example with centered windows
+    let mut windower = Windower::new(SliceRingImpl::new(), 4, 2);
+    windower.set_tail(Tail::Pad(b'_'));
+    windower.pad_front(b'_');
+    windower.push(b"ABCDEFGHIJK");
+    windower.finish();
+    let mut output = Vec::new();
+    while let Some(window) = windower.next_window() {
+        output.extend_from_slice(window);
+    }
+    assert_eq!(&output[..], &b"__ABABCDCDEFEFGHGHIJIJK_"[..]);
+}
+
+#[test]
+#[should_panic(expected = "push after finish")]
+fn test_push_after_finish() {
+    let mut windower = Windower::new(SliceRingImpl::new(), 4, 2);
+    windower.finish();
+    windower.push(&[1]);
+}
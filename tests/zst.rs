@@ -0,0 +1,95 @@
+use std::cell::Cell;
+use std::slice;
+
+extern crate strider;
+use strider::SliceRing;
+use strider::SliceRingExt;
+use strider::SliceRingImpl;
+use strider::TryReserveError;
+
+/// a slice of `len` zero sized values without allocating or cloning
+fn units(len: usize) -> &'static [()] {
+    unsafe { slice::from_raw_parts(std::ptr::NonNull::dangling().as_ptr(), len) }
+}
+
+#[test]
+fn test_zst_push_read_drop() {
+    let mut ring = SliceRingImpl::<()>::new();
+    assert_eq!(ring.capacity(), (1 << (usize::BITS - 1)) - 1);
+    assert_eq!(ring.len(), 0);
+
+    ring.push_many_back(&[(); 1000]);
+    assert_eq!(ring.len(), 1000);
+    let mut output = [(); 300];
+    assert_eq!(ring.read_many_front(&mut output[..]), 300);
+    assert_eq!(ring.drop_many_front(300), 300);
+    assert_eq!(ring.len(), 700);
+    assert_eq!(ring.drop_many_front(1000), 700);
+    assert!(ring.is_empty());
+}
+
+#[test]
+fn test_zst_slice_ring() {
+    let mut ring = SliceRingImpl::<()>::with_capacity(3);
+    for _ in 0..100 {
+        ring.push_many_back(&[(); 77]);
+        ring.drop_many_front(50);
+    }
+    assert_eq!(ring.len(), 2700);
+}
+
+#[test]
+fn test_zst_capacity_overflow() {
+    let mut ring = SliceRingImpl::<()>::new();
+    ring.push_many_back(&[(); 5]);
+    let capacity = ring.capacity();
+
+    assert_eq!(ring.try_push_many_back(units(capacity - 4)),
+               Err(TryReserveError::CapacityOverflow));
+    assert_eq!(ring.try_push_many_back(units(usize::MAX)),
+               Err(TryReserveError::CapacityOverflow));
+    // ring is unchanged
+    assert_eq!(ring.len(), 5);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_zst_push_many_back_capacity_overflow() {
+    let mut ring = SliceRingImpl::<()>::new();
+    ring.push_many_back(&[(); 5]);
+    ring.push_many_back(units(usize::MAX - 1));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_with_capacity_overflow() {
+    SliceRingImpl::<u64>::with_capacity(usize::MAX / 4);
+}
+
+thread_local!(static UNIT_DROPS: Cell<usize> = const { Cell::new(0) });
+
+#[derive(Clone)]
+struct DropUnit;
+
+impl Drop for DropUnit {
+    fn drop(&mut self) {
+        UNIT_DROPS.with(|drops| drops.set(drops.get() + 1));
+    }
+}
+
+fn unit_drops() -> usize {
+    UNIT_DROPS.with(|drops| drops.get())
+}
+
+#[test]
+fn test_zst_drops_elements() {
+    let mut ring = SliceRingImpl::new();
+    let input = vec![DropUnit, DropUnit, DropUnit, DropUnit];
+    ring.push_many_back(&input[..]);
+    drop(input);
+    assert_eq!(unit_drops(), 4);
+    assert_eq!(ring.drop_many_front(3), 3);
+    assert_eq!(unit_drops(), 7);
+    drop(ring);
+    assert_eq!(unit_drops(), 8);
+}